@@ -1,41 +1,16 @@
 use vertigo::{bind_rc, main, prelude::*};
-use vertigo_forms::form::{DataSection as DS, FormData, FormExport, ModelForm};
+use vertigo_forms::form::{FormModel, ModelForm};
 
-#[derive(Clone, Default, PartialEq)]
+#[derive(Clone, Default, PartialEq, FormModel)]
+#[form(top_controls)]
 struct Car {
     brand: String,
     model: String,
     year: String,
+    #[form(kind = "list", options = vec!["petrol".into(), "diesel".into(), "electric".into()])]
     engine: String,
 }
 
-impl From<Car> for FormData {
-    fn from(value: Car) -> Self {
-        FormData::default()
-            .add_top_controls()
-            .with(DS::with_string_field("Brand", "brand", &value.brand))
-            .with(DS::with_string_field("Model", "model", &value.model))
-            .with(DS::with_string_field("Year", "year", &value.year))
-            .with(DS::new("Engine").add_list_field(
-                "engine",
-                Some(&value.engine),
-                vec!["petrol".into(), "diesel".into(), "electric".into()],
-            ))
-    }
-}
-
-impl From<FormExport> for Car {
-    fn from(value: FormExport) -> Self {
-        vertigo::log::info!("FormExport {}", value.get_string("engine"));
-        Self {
-            brand: value.get_string("brand"),
-            model: value.get_string("model"),
-            year: value.get_string("year"),
-            engine: value.list_or_default("engine"),
-        }
-    }
-}
-
 #[main]
 fn render() -> DomNode {
     let car = Value::new(Car::default());
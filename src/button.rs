@@ -1,6 +1,8 @@
 use std::rc::Rc;
 use vertigo::{component, css, dom};
 
+use crate::theme::var;
+
 #[derive(Clone, Copy, PartialEq)]
 pub enum ButtonColor {
     Primary,
@@ -22,50 +24,24 @@ pub fn Button(
     color: ButtonColor,
     variant: ButtonVariant,
 ) {
-    let style = match (variant, color) {
-        (ButtonVariant::Text, ButtonColor::Primary) => css! {"
-            font-size: 0.8rem; cursor: pointer; font-weight: 600;
-            color: #007bff;
-            :hover { text-decoration: underline; }
-        "},
-        (ButtonVariant::Text, ButtonColor::Success) => css! {"
-            font-size: 0.8rem; cursor: pointer; font-weight: 600;
-            color: #28a745;
-            :hover { text-decoration: underline; }
-        "},
-        (ButtonVariant::Text, ButtonColor::Danger) => css! {"
-            font-size: 0.8rem; cursor: pointer; font-weight: 600;
-            color: #dc3545;
-            :hover { text-decoration: underline; }
-        "},
-        (ButtonVariant::Text, ButtonColor::Secondary) => css! {"
-            font-size: 0.8rem; cursor: pointer; font-weight: 600;
-            color: #6c757d;
+    let color = match color {
+        ButtonColor::Primary => var::PRIMARY,
+        ButtonColor::Success => var::SUCCESS,
+        ButtonColor::Danger => var::DANGER,
+        ButtonColor::Secondary => var::SECONDARY,
+    };
+
+    let style = match variant {
+        ButtonVariant::Text => css! {"
+            font-size: {var::FONT_SIZE_SM}; cursor: pointer; font-weight: 600;
+            color: {color};
             :hover { text-decoration: underline; }
         "},
-        (ButtonVariant::Outline, ButtonColor::Danger) => css! {"
-            font-size: 0.8rem; cursor: pointer; font-weight: 700;
-            background: #fff; padding: 4px 12px; border-radius: 4px;
-            border: 1px solid #dc3545; color: #dc3545;
-            :hover { background: #dc3545; color: #fff; }
-        "},
-        (ButtonVariant::Outline, ButtonColor::Primary) => css! {"
-            font-size: 0.8rem; cursor: pointer; font-weight: 700;
-            background: #fff; padding: 4px 12px; border-radius: 4px;
-            border: 1px solid #007bff; color: #007bff;
-            :hover { background: #007bff; color: #fff; }
-        "},
-        (ButtonVariant::Outline, ButtonColor::Success) => css! {"
-            font-size: 0.8rem; cursor: pointer; font-weight: 700;
-            background: #fff; padding: 4px 12px; border-radius: 4px;
-            border: 1px solid #28a745; color: #28a745;
-            :hover { background: #28a745; color: #fff; }
-        "},
-        (ButtonVariant::Outline, ButtonColor::Secondary) => css! {"
-            font-size: 0.8rem; cursor: pointer; font-weight: 700;
-            background: #fff; padding: 4px 12px; border-radius: 4px;
-            border: 1px solid #6c757d; color: #6c757d;
-            :hover { background: #6c757d; color: #fff; }
+        ButtonVariant::Outline => css! {"
+            font-size: {var::FONT_SIZE_SM}; cursor: pointer; font-weight: 700;
+            background: {var::BACKGROUND}; padding: {var::SPACING_SM} 12px; border-radius: {var::RADIUS_SM};
+            border: 1px solid; border-color: {color}; color: {color};
+            :hover { background: {color}; color: {var::ON_ACCENT}; }
         "},
     };
 
@@ -85,15 +61,15 @@ pub fn TableButton(label: String, on_click: Rc<dyn Fn() + 'static>) {
         <div
             on_click={move |_| on_click()}
             css={css! {"
-                padding: 8px 16px;
-                background: #232323;
-                color: #fff;
-                border-radius: 8px;
-                font-size: 0.9rem;
+                padding: {var::SPACING_MD} {var::SPACING_LG};
+                background: {var::CONTRAST};
+                color: {var::BACKGROUND};
+                border-radius: {var::RADIUS_MD};
+                font-size: {var::FONT_SIZE};
                 font-weight: 600;
                 cursor: pointer;
                 transition: all 0.2s;
-                :hover { background: #444; }
+                :hover { background: {var::CONTRAST_HOVER}; }
             "}}
         >
             { label }
@@ -0,0 +1,356 @@
+use std::rc::Rc;
+
+use crate::locale;
+
+use super::{Date, DateTime, Time, Weekday};
+
+/// Locale-specific way of displaying and entering dates and times.
+///
+/// Patterns support following tokens:
+///
+/// * `%Y` - year, `%m` - month (01-12), `%d` - day of month (01-31), `%B` - month name,
+/// * `%H` - hour (00-23), `%I` - hour (01-12), `%p` - AM/PM, `%M` - minute, `%S` - second,
+/// * `%%` - literal `%`.
+///
+/// When parsing, leading zeros are optional and month names are matched case-insensitively.
+///
+/// ```rust
+/// use vertigo_forms::date::{Date, DateFormat, Time};
+///
+/// let format = DateFormat::us();
+/// let date = Date::new(2024, 7, 4).unwrap();
+/// assert_eq!(format.format_date(&date), "07/04/2024");
+/// assert_eq!(format.parse_date("7/4/2024"), Some(date));
+/// assert_eq!(format.format_time(&Time::new(15, 30, 0).unwrap()), "03:30 PM");
+/// assert_eq!(format.parse_time("12:15 am"), Time::new(0, 15, 0));
+///
+/// let format = DateFormat::european().with_date("%d %B %Y");
+/// assert_eq!(format.format_date(&date), "04 July 2024");
+/// assert_eq!(format.parse_date("4 july 2024"), Some(date));
+/// assert_eq!(format.parse_date("31 june 2024"), None);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct DateFormat {
+    /// Pattern for dates
+    pub date: Rc<String>,
+    /// Pattern for times
+    pub time: Rc<String>,
+    /// First column of calendar
+    pub first_weekday: Weekday,
+    /// Names of months, starting from January
+    pub month_names: Rc<Vec<String>>,
+    /// Short names of days of week, starting from Monday
+    pub weekday_names: Rc<Vec<String>>,
+}
+
+impl Default for DateFormat {
+    fn default() -> Self {
+        Self::iso()
+    }
+}
+
+impl DateFormat {
+    /// `2024-12-31`, `23:59`, week starting on Monday
+    pub fn iso() -> Self {
+        let locale = locale();
+        Self {
+            date: Rc::new("%Y-%m-%d".to_string()),
+            time: Rc::new("%H:%M".to_string()),
+            first_weekday: Weekday::Monday,
+            month_names: Rc::new(locale.month_names.clone()),
+            weekday_names: Rc::new(locale.weekday_names.clone()),
+        }
+    }
+
+    /// `12/31/2024`, `11:59 PM`, week starting on Sunday
+    pub fn us() -> Self {
+        Self {
+            first_weekday: Weekday::Sunday,
+            ..Self::iso().with_date("%m/%d/%Y").with_time("%I:%M %p")
+        }
+    }
+
+    /// `31.12.2024`, `23:59`, week starting on Monday
+    pub fn european() -> Self {
+        Self::iso().with_date("%d.%m.%Y")
+    }
+
+    pub fn with_date(self, pattern: impl Into<String>) -> Self {
+        Self {
+            date: Rc::new(pattern.into()),
+            ..self
+        }
+    }
+
+    pub fn with_time(self, pattern: impl Into<String>) -> Self {
+        Self {
+            time: Rc::new(pattern.into()),
+            ..self
+        }
+    }
+
+    pub fn format_date(&self, date: &Date) -> String {
+        self.format(&self.date, Some(date), None)
+    }
+
+    pub fn format_time(&self, time: &Time) -> String {
+        self.format(&self.time, None, Some(time))
+    }
+
+    /// Date and time separated by space.
+    pub fn format_date_time(&self, date_time: &DateTime) -> String {
+        format!(
+            "{} {}",
+            self.format_date(&date_time.date),
+            self.format_time(&date_time.time)
+        )
+    }
+
+    pub fn parse_date(&self, text: &str) -> Option<Date> {
+        let parts = self.parse(&self.date, text)?;
+        Date::new(parts.year?, parts.month?, parts.day?)
+    }
+
+    pub fn parse_time(&self, text: &str) -> Option<Time> {
+        self.parse(&self.time, text)?.time()
+    }
+
+    /// Date and time separated by whitespace.
+    pub fn parse_date_time(&self, text: &str) -> Option<DateTime> {
+        let parts = self.parse(&format!("{} {}", self.date, self.time), text)?;
+        Some(DateTime::new(
+            Date::new(parts.year?, parts.month?, parts.day?)?,
+            parts.time()?,
+        ))
+    }
+
+    /// Name of the month (1-12).
+    pub fn month_name(&self, month: u32) -> &str {
+        (month as usize)
+            .checked_sub(1)
+            .and_then(|idx| self.month_names.get(idx))
+            .map(String::as_str)
+            .unwrap_or_default()
+    }
+
+    /// Short name of the day of week.
+    pub fn weekday_name(&self, weekday: Weekday) -> &str {
+        self.weekday_names
+            .get(weekday.number_from_monday() as usize)
+            .map(String::as_str)
+            .unwrap_or_default()
+    }
+
+    fn format(&self, pattern: &str, date: Option<&Date>, time: Option<&Time>) -> String {
+        let mut result = String::new();
+        let mut chars = pattern.chars();
+        while let Some(char) = chars.next() {
+            if char != '%' {
+                result.push(char);
+                continue;
+            }
+            match (chars.next(), date, time) {
+                (Some('Y'), Some(date), _) => result += &format!("{:04}", date.year),
+                (Some('m'), Some(date), _) => result += &format!("{:02}", date.month),
+                (Some('d'), Some(date), _) => result += &format!("{:02}", date.day),
+                (Some('B'), Some(date), _) => result += self.month_name(date.month),
+                (Some('H'), _, Some(time)) => result += &format!("{:02}", time.hour),
+                (Some('I'), _, Some(time)) => {
+                    result += &format!("{:02}", (time.hour + 11) % 12 + 1)
+                }
+                (Some('p'), _, Some(time)) => result += if time.hour < 12 { "AM" } else { "PM" },
+                (Some('M'), _, Some(time)) => result += &format!("{:02}", time.minute),
+                (Some('S'), _, Some(time)) => result += &format!("{:02}", time.second),
+                (Some('%'), _, _) => result.push('%'),
+                _ => {}
+            }
+        }
+        result
+    }
+
+    fn parse(&self, pattern: &str, text: &str) -> Option<ParsedParts> {
+        let mut parts = ParsedParts::default();
+        let mut text = text.trim();
+        let mut chars = pattern.chars();
+        while let Some(char) = chars.next() {
+            if char.is_whitespace() {
+                text = text.trim_start();
+                continue;
+            }
+            if char != '%' {
+                text = text.strip_prefix(char)?;
+                continue;
+            }
+            match chars.next()? {
+                'Y' => parts.year = Some(take_number(&mut text, 6)? as i32),
+                'm' => parts.month = Some(take_number(&mut text, 2)?),
+                'd' => parts.day = Some(take_number(&mut text, 2)?),
+                'H' => parts.hour = Some(take_number(&mut text, 2)?),
+                'I' => {
+                    let hour = take_number(&mut text, 2).filter(|hour| (1..=12).contains(hour))?;
+                    parts.hour = Some(hour);
+                    parts.pm.get_or_insert(false);
+                }
+                'M' => parts.minute = Some(take_number(&mut text, 2)?),
+                'S' => parts.second = Some(take_number(&mut text, 2)?),
+                'p' => {
+                    let (pm, rest) = take_prefix(text, ["am", "pm"])?;
+                    parts.pm = Some(pm == 1);
+                    text = rest;
+                }
+                'B' => {
+                    let (month, rest) = take_prefix(text, self.month_names.iter())?;
+                    parts.month = Some(month as u32 + 1);
+                    text = rest;
+                }
+                '%' => text = text.strip_prefix('%')?,
+                _ => return None,
+            }
+        }
+        text.is_empty().then_some(parts)
+    }
+}
+
+#[derive(Default)]
+struct ParsedParts {
+    year: Option<i32>,
+    month: Option<u32>,
+    day: Option<u32>,
+    hour: Option<u32>,
+    minute: Option<u32>,
+    second: Option<u32>,
+    /// Set if hour is in 12-hour format
+    pm: Option<bool>,
+}
+
+impl ParsedParts {
+    fn time(&self) -> Option<Time> {
+        let hour = match self.pm {
+            Some(pm) => self.hour? % 12 + if pm { 12 } else { 0 },
+            None => self.hour?,
+        };
+        Time::new(hour, self.minute?, self.second.unwrap_or_default())
+    }
+}
+
+/// Consume up to `max_digits` digits from the beginning of text.
+fn take_number(text: &mut &str, max_digits: usize) -> Option<u32> {
+    let len = text
+        .chars()
+        .take(max_digits)
+        .take_while(char::is_ascii_digit)
+        .count();
+    let number = text[..len].parse().ok()?;
+    *text = &text[len..];
+    Some(number)
+}
+
+/// Consume the longest of provided words (case-insensitive), returns its index.
+fn take_prefix<S: AsRef<str>>(
+    text: &str,
+    words: impl IntoIterator<Item = S>,
+) -> Option<(usize, &str)> {
+    let lowercase = text.to_lowercase();
+    words
+        .into_iter()
+        .enumerate()
+        .map(|(idx, word)| (idx, word.as_ref().to_lowercase()))
+        .filter(|(_, word)| !word.is_empty() && lowercase.starts_with(word.as_str()))
+        .max_by_key(|(_, word)| word.len())
+        .and_then(|(idx, word)| Some((idx, text.get(word.len()..)?)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DateFormat;
+    use crate::date::{Date, DateTime, Time};
+
+    fn date(year: i32, month: u32, day: u32) -> Date {
+        Date::new(year, month, day).unwrap()
+    }
+
+    fn time(hour: u32, minute: u32) -> Time {
+        Time::new(hour, minute, 0).unwrap()
+    }
+
+    #[test]
+    fn iso() {
+        let format = DateFormat::iso();
+        assert_eq!(format.format_date(&date(2024, 3, 5)), "2024-03-05");
+        assert_eq!(format.parse_date("2024-03-05"), Some(date(2024, 3, 5)));
+        assert_eq!(format.parse_date("2024-3-5"), Some(date(2024, 3, 5)));
+        assert_eq!(format.format_time(&time(7, 5)), "07:05");
+        assert_eq!(format.parse_time("23:59"), Some(time(23, 59)));
+        assert_eq!(format.parse_time("24:00"), None);
+        let date_time = DateTime::new(date(2024, 3, 5), time(7, 5));
+        assert_eq!(format.format_date_time(&date_time), "2024-03-05 07:05");
+        assert_eq!(format.parse_date_time("2024-03-05  7:05"), Some(date_time));
+    }
+
+    #[test]
+    fn us() {
+        let format = DateFormat::us();
+        assert_eq!(format.format_date(&date(2024, 12, 31)), "12/31/2024");
+        assert_eq!(format.parse_date("12/31/2024"), Some(date(2024, 12, 31)));
+        assert_eq!(format.parse_date("31/12/2024"), None);
+    }
+
+    #[test]
+    fn european() {
+        let format = DateFormat::european();
+        assert_eq!(format.format_date(&date(2024, 12, 31)), "31.12.2024");
+        assert_eq!(format.parse_date("31.12.2024"), Some(date(2024, 12, 31)));
+        assert_eq!(format.parse_date("1.2.2024"), Some(date(2024, 2, 1)));
+        assert_eq!(format.parse_date("31-12-2024"), None);
+    }
+
+    #[test]
+    fn twelve_hour_clock() {
+        let format = DateFormat::us();
+        assert_eq!(format.format_time(&time(0, 0)), "12:00 AM");
+        assert_eq!(format.format_time(&time(0, 30)), "12:30 AM");
+        assert_eq!(format.format_time(&time(11, 59)), "11:59 AM");
+        assert_eq!(format.format_time(&time(12, 0)), "12:00 PM");
+        assert_eq!(format.format_time(&time(12, 45)), "12:45 PM");
+        assert_eq!(format.format_time(&time(23, 59)), "11:59 PM");
+
+        assert_eq!(format.parse_time("12:00 AM"), Some(time(0, 0)));
+        assert_eq!(format.parse_time("12:30 am"), Some(time(0, 30)));
+        assert_eq!(format.parse_time("12:00 PM"), Some(time(12, 0)));
+        assert_eq!(format.parse_time("12:45 pm"), Some(time(12, 45)));
+        assert_eq!(format.parse_time("1:05 PM"), Some(time(13, 5)));
+        assert_eq!(format.parse_time("0:30 AM"), None);
+        assert_eq!(format.parse_time("13:00 PM"), None);
+        assert_eq!(format.parse_time("11:00"), None);
+    }
+
+    #[test]
+    fn month_names() {
+        let format = DateFormat::iso().with_date("%B %d, %Y");
+        assert_eq!(format.format_date(&date(2024, 9, 1)), "September 01, 2024");
+        assert_eq!(
+            format.parse_date("september 1, 2024"),
+            Some(date(2024, 9, 1))
+        );
+        assert_eq!(
+            format.parse_date("SEPTEMBER 1, 2024"),
+            Some(date(2024, 9, 1))
+        );
+        assert_eq!(format.parse_date("Septembre 1, 2024"), None);
+    }
+
+    #[test]
+    fn rejects_invalid_dates() {
+        assert_eq!(DateFormat::iso().parse_date("2023-02-29"), None);
+        assert_eq!(
+            DateFormat::iso().parse_date("2024-02-29"),
+            Some(date(2024, 2, 29))
+        );
+        assert_eq!(DateFormat::iso().parse_date("2024-13-01"), None);
+        assert_eq!(DateFormat::iso().parse_date("2024-04-31"), None);
+        assert_eq!(DateFormat::iso().parse_date("2024-00-10"), None);
+        assert_eq!(DateFormat::us().parse_date("02/29/2023"), None);
+        assert_eq!(DateFormat::european().parse_date("29.02.2023"), None);
+        assert_eq!(DateFormat::iso().parse_date("2024-01-01x"), None);
+    }
+}
@@ -0,0 +1,387 @@
+//! Calendar types used by date and time fields.
+//!
+//! Implemented without any browser dependencies, so the arithmetic can be tested anywhere.
+
+use std::{fmt::Display, str::FromStr};
+
+mod format;
+pub use format::DateFormat;
+
+/// Calendar date (proleptic Gregorian).
+///
+/// ```rust
+/// use vertigo_forms::date::{Date, Weekday};
+///
+/// let date: Date = "2024-02-28".parse().unwrap();
+/// assert_eq!(date.add_days(1).to_string(), "2024-02-29");
+/// assert_eq!(date.add_days(2).to_string(), "2024-03-01");
+/// assert_eq!(date.weekday(), Weekday::Wednesday);
+///
+/// let date = Date::new(2024, 1, 31).unwrap();
+/// assert_eq!(date.add_months(1), Date::new(2024, 2, 29).unwrap());
+/// assert_eq!(date.add_months(-2), Date::new(2023, 11, 30).unwrap());
+///
+/// assert_eq!(Date::new(2023, 2, 29), None);
+/// assert_eq!(Date::from_days_since_epoch(0).to_string(), "1970-01-01");
+/// assert_eq!(Date::new(2000, 3, 1).unwrap().days_since_epoch(), 11017);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Date {
+    year: i32,
+    month: u32,
+    day: u32,
+}
+
+impl Date {
+    /// Returns `None` if there is no such day in the calendar.
+    pub fn new(year: i32, month: u32, day: u32) -> Option<Self> {
+        ((1..=12).contains(&month) && (1..=days_in_month(year, month)).contains(&day))
+            .then_some(Self { year, month, day })
+    }
+
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    /// Month number, starting from 1.
+    pub fn month(&self) -> u32 {
+        self.month
+    }
+
+    /// Day of month, starting from 1.
+    pub fn day(&self) -> u32 {
+        self.day
+    }
+
+    pub fn weekday(&self) -> Weekday {
+        // 1970-01-01 was a Thursday
+        Weekday::from_monday((self.days_since_epoch() + 3).rem_euclid(7) as u32)
+    }
+
+    /// Number of days since 1970-01-01 (negative for earlier dates).
+    pub fn days_since_epoch(&self) -> i64 {
+        // Based on http://howardhinnant.github.io/date_algorithms.html#days_from_civil
+        let year = i64::from(self.year) - i64::from(self.month <= 2);
+        let era = year.div_euclid(400);
+        let year_of_era = year.rem_euclid(400);
+        let month = i64::from(self.month);
+        let day_of_year =
+            (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + i64::from(self.day) - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+        era * 146097 + day_of_era - 719468
+    }
+
+    /// Inverse of [days_since_epoch](Date::days_since_epoch).
+    pub fn from_days_since_epoch(days: i64) -> Self {
+        // Based on http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+        let days = days + 719468;
+        let era = days.div_euclid(146097);
+        let day_of_era = days.rem_euclid(146097);
+        let year_of_era =
+            (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let shifted_month = (5 * day_of_year + 2) / 153;
+        let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+        let month = if shifted_month < 10 {
+            shifted_month + 3
+        } else {
+            shifted_month - 9
+        };
+        let year = year_of_era + era * 400 + i64::from(month <= 2);
+        Self {
+            year: year as i32,
+            month: month as u32,
+            day: day as u32,
+        }
+    }
+
+    pub fn add_days(&self, days: i64) -> Self {
+        Self::from_days_since_epoch(self.days_since_epoch() + days)
+    }
+
+    /// Moves by provided number of months, clamping the day to the length of target month.
+    pub fn add_months(&self, months: i32) -> Self {
+        let months_total = self.year * 12 + self.month as i32 - 1 + months;
+        let year = months_total.div_euclid(12);
+        let month = months_total.rem_euclid(12) as u32 + 1;
+        Self {
+            year,
+            month,
+            day: self.day.min(days_in_month(year, month)),
+        }
+    }
+
+    pub fn first_day_of_month(&self) -> Self {
+        Self { day: 1, ..*self }
+    }
+}
+
+impl Default for Date {
+    /// 1970-01-01
+    fn default() -> Self {
+        Self::from_days_since_epoch(0)
+    }
+}
+
+impl Display for Date {
+    /// ISO 8601 format (`YYYY-MM-DD`)
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+impl FromStr for Date {
+    type Err = DateParseError;
+
+    /// Parse ISO 8601 format (`YYYY-MM-DD`)
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        DateFormat::iso().parse_date(s).ok_or(DateParseError)
+    }
+}
+
+/// Time of day with precision of seconds.
+///
+/// ```rust
+/// use vertigo_forms::date::Time;
+///
+/// let time: Time = "9:05".parse().unwrap();
+/// assert_eq!(time.to_string(), "09:05");
+/// assert_eq!(Time::new(23, 59, 30).unwrap().to_string(), "23:59:30");
+/// assert_eq!(Time::new(24, 0, 0), None);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Time {
+    hour: u32,
+    minute: u32,
+    second: u32,
+}
+
+impl Time {
+    /// Returns `None` if values are out of range.
+    pub fn new(hour: u32, minute: u32, second: u32) -> Option<Self> {
+        (hour < 24 && minute < 60 && second < 60).then_some(Self {
+            hour,
+            minute,
+            second,
+        })
+    }
+
+    pub fn hour(&self) -> u32 {
+        self.hour
+    }
+
+    pub fn minute(&self) -> u32 {
+        self.minute
+    }
+
+    pub fn second(&self) -> u32 {
+        self.second
+    }
+}
+
+impl Display for Time {
+    /// `HH:MM`, or `HH:MM:SS` if seconds are not zero
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:02}:{:02}", self.hour, self.minute)?;
+        if self.second > 0 {
+            write!(f, ":{:02}", self.second)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Time {
+    type Err = DateParseError;
+
+    /// Parse `HH:MM` or `HH:MM:SS`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let format = DateFormat::iso();
+        format
+            .parse_time(s)
+            .or_else(|| format.with_time("%H:%M:%S").parse_time(s))
+            .ok_or(DateParseError)
+    }
+}
+
+/// Date with time of day.
+///
+/// ```rust
+/// use vertigo_forms::date::DateTime;
+///
+/// let date_time: DateTime = "2024-12-31T23:30".parse().unwrap();
+/// assert_eq!(date_time.date().year(), 2024);
+/// assert_eq!(date_time.to_string(), "2024-12-31T23:30");
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DateTime {
+    date: Date,
+    time: Time,
+}
+
+impl DateTime {
+    pub fn new(date: Date, time: Time) -> Self {
+        Self { date, time }
+    }
+
+    pub fn date(&self) -> Date {
+        self.date
+    }
+
+    pub fn time(&self) -> Time {
+        self.time
+    }
+}
+
+impl Display for DateTime {
+    /// ISO 8601 format (`YYYY-MM-DDTHH:MM[:SS]`)
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}T{}", self.date, self.time)
+    }
+}
+
+impl FromStr for DateTime {
+    type Err = DateParseError;
+
+    /// Parse ISO 8601 format (`YYYY-MM-DDTHH:MM[:SS]`), space is also accepted as separator
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (date, time) = s.trim().split_once(['T', ' ']).ok_or(DateParseError)?;
+        Ok(Self::new(date.parse()?, time.parse()?))
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DateParseError;
+
+impl Display for DateParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Invalid date or time")
+    }
+}
+
+impl std::error::Error for DateParseError {}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Weekday {
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+impl Weekday {
+    const ALL: [Self; 7] = [
+        Self::Monday,
+        Self::Tuesday,
+        Self::Wednesday,
+        Self::Thursday,
+        Self::Friday,
+        Self::Saturday,
+        Self::Sunday,
+    ];
+
+    /// Monday is 0, Sunday is 6.
+    pub fn number_from_monday(&self) -> u32 {
+        *self as u32
+    }
+
+    fn from_monday(number: u32) -> Self {
+        Self::ALL[number as usize % 7]
+    }
+
+    /// Number of days since provided first day of week (0 to 6).
+    ///
+    /// ```rust
+    /// use vertigo_forms::date::Weekday;
+    ///
+    /// assert_eq!(Weekday::Monday.days_since(Weekday::Sunday), 1);
+    /// assert_eq!(Weekday::Sunday.days_since(Weekday::Monday), 6);
+    /// ```
+    pub fn days_since(&self, first_weekday: Weekday) -> u32 {
+        (self.number_from_monday() + 7 - first_weekday.number_from_monday()) % 7
+    }
+
+    /// All days of week, starting from provided one.
+    pub fn week_from(first_weekday: Weekday) -> impl Iterator<Item = Weekday> {
+        (0..7).map(move |i| Self::from_monday(first_weekday.number_from_monday() + i))
+    }
+}
+
+pub fn is_leap_year(year: i32) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+/// Number of days in month (1-12), 0 for invalid month.
+pub fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        _ => 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Date, days_in_month, is_leap_year};
+
+    fn date(year: i32, month: u32, day: u32) -> Date {
+        Date::new(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn add_days_across_month_and_year_ends() {
+        assert_eq!(date(2024, 1, 31).add_days(1), date(2024, 2, 1));
+        assert_eq!(date(2023, 2, 28).add_days(1), date(2023, 3, 1));
+        assert_eq!(date(2023, 12, 31).add_days(1), date(2024, 1, 1));
+        assert_eq!(date(2024, 1, 1).add_days(-1), date(2023, 12, 31));
+        assert_eq!(date(2024, 3, 1).add_days(-1), date(2024, 2, 29));
+        assert_eq!(date(2024, 1, 1).add_days(366), date(2025, 1, 1));
+    }
+
+    #[test]
+    fn add_months_clamps_day() {
+        assert_eq!(date(2023, 1, 31).add_months(1), date(2023, 2, 28));
+        assert_eq!(date(2024, 1, 31).add_months(1), date(2024, 2, 29));
+        assert_eq!(date(2024, 3, 31).add_months(1), date(2024, 4, 30));
+        assert_eq!(date(2024, 12, 15).add_months(1), date(2025, 1, 15));
+        assert_eq!(date(2024, 1, 15).add_months(-1), date(2023, 12, 15));
+        assert_eq!(date(2024, 2, 29).add_months(12), date(2025, 2, 28));
+        assert_eq!(date(2024, 5, 31).add_months(-27), date(2022, 2, 28));
+    }
+
+    #[test]
+    fn leap_years() {
+        assert!(!is_leap_year(1900));
+        assert!(is_leap_year(2000));
+        assert!(is_leap_year(2024));
+        assert!(!is_leap_year(2023));
+        assert_eq!(days_in_month(1900, 2), 28);
+        assert_eq!(days_in_month(2000, 2), 29);
+        assert_eq!(days_in_month(2024, 2), 29);
+        assert_eq!(Date::new(1900, 2, 29), None);
+        assert!(Date::new(2000, 2, 29).is_some());
+    }
+
+    #[test]
+    fn days_since_epoch_round_trip() {
+        assert_eq!(date(1970, 1, 1).days_since_epoch(), 0);
+        assert_eq!(date(1969, 12, 31).days_since_epoch(), -1);
+        assert_eq!(date(1900, 1, 1).days_since_epoch(), -25567);
+        assert_eq!(date(9999, 12, 31).days_since_epoch(), 2932896);
+
+        for days in [
+            -1_000_000, -719_528, -25_567, -366, -1, 0, 1, 59, 11_017, 2_932_896, 1_000_000,
+        ] {
+            let date = Date::from_days_since_epoch(days);
+            assert_eq!(date.days_since_epoch(), days, "{date}");
+            assert_eq!(Date::new(date.year(), date.month(), date.day()), Some(date));
+        }
+        // Year 0 and before
+        assert_eq!(Date::from_days_since_epoch(-719_528), date(0, 1, 1));
+        assert_eq!(date(-1, 12, 31).add_days(1), date(0, 1, 1));
+    }
+}
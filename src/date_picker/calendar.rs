@@ -0,0 +1,160 @@
+use std::rc::Rc;
+use vertigo::{Computed, Css, DomNode, Value, bind, component, css, dom, dom_element, transaction};
+
+use crate::{
+    date::{Date, DateFormat, Weekday},
+    theme::var,
+};
+
+use super::today;
+
+#[derive(Clone)]
+pub struct CalendarParams {
+    pub format: DateFormat,
+    /// Earliest day that can be selected
+    pub min: Option<Date>,
+    /// Latest day that can be selected
+    pub max: Option<Date>,
+    pub day_css: Css,
+    pub other_month_day_add_css: Css,
+    pub selected_day_add_css: Css,
+}
+
+impl Default for CalendarParams {
+    fn default() -> Self {
+        Self {
+            format: Default::default(),
+            min: None,
+            max: None,
+            day_css: css! {"
+                cursor: pointer;
+                border: none;
+                background: none;
+                padding: {var::SPACING_SM};
+                color: {var::TEXT};
+                :hover { background: {var::HOVER}; }
+                :disabled { cursor: default; color: {var::DISABLED}; background: none; }
+            "},
+            other_month_day_add_css: css! {"color: {var::TEXT_MUTED};"},
+            selected_day_add_css: css! {"
+                background: {var::PRIMARY};
+                color: {var::ON_ACCENT};
+                :hover { background: {var::PRIMARY_HOVER}; }
+            "},
+        }
+    }
+}
+
+/// Month view with days to pick from.
+///
+/// Shows the month of selected day (or current month if none is selected).
+#[component]
+pub fn Calendar(
+    selected: Computed<Option<Date>>,
+    on_select: Rc<dyn Fn(Date)>,
+    params: CalendarParams,
+) {
+    let CalendarParams {
+        format, min, max, ..
+    } = params.clone();
+
+    let initial = transaction(|ctx| selected.get(ctx)).unwrap_or_else(|| {
+        let today = today();
+        let today = min.map_or(today, |min| today.max(min));
+        max.map_or(today, |max| today.min(max))
+    });
+    // Month chosen using navigation, valid only as long as selected day stays the same,
+    // so the calendar follows selected day if it changes outside of calendar
+    let navigated = Value::new(None::<(Option<Date>, Date)>);
+    let shown_month = Computed::from(bind!(navigated, selected, |ctx| {
+        let selected = selected.get(ctx);
+        match navigated.get(ctx) {
+            Some((navigated_from, month)) if navigated_from == selected => month,
+            _ => selected.unwrap_or(initial).first_day_of_month(),
+        }
+    }));
+
+    let nav_css = css! {"
+        display: flex;
+        justify-content: space-between;
+        align-items: center;
+        gap: 5px;
+    "};
+    let nav_button_css = css! {"
+        cursor: pointer;
+        border: none;
+        background: none;
+    "};
+
+    let navigate = Rc::new(bind!(navigated, selected, shown_month, |months: i32| {
+        let (selected, month) = transaction(|ctx| (selected.get(ctx), shown_month.get(ctx)));
+        navigated.set(Some((selected, month.add_months(months))));
+    }));
+    let on_prev = bind!(navigate, |_| navigate(-1));
+    let on_next = bind!(navigate, |_| navigate(1));
+
+    let header = shown_month.map(bind!(format, |month| {
+        format!("{} {}", format.month_name(month.month()), month.year())
+    }));
+
+    let grid_css = css! {"
+        display: grid;
+        grid-template-columns: repeat(7, 1fr);
+        text-align: center;
+    "};
+
+    let grid = Computed::from(bind!(shown_month, selected, |ctx| (
+        shown_month.get(ctx),
+        selected.get(ctx)
+    )))
+    .render_value(move |(month, selected)| {
+        let first_weekday = format.first_weekday;
+
+        let weekdays = Weekday::week_from(first_weekday)
+            .map(|weekday| dom! { <b>{format.weekday_name(weekday)}</b> })
+            .collect::<Vec<_>>();
+
+        let start = month.add_days(-i64::from(month.weekday().days_since(first_weekday)));
+        let days = (0..42)
+            .map(|idx| start.add_days(idx))
+            .map(|day| {
+                let mut css = params.day_css.clone();
+                if day.month() != month.month() {
+                    css += &params.other_month_day_add_css;
+                }
+                if Some(day) == selected {
+                    css += &params.selected_day_add_css;
+                }
+                let on_click = bind!(on_select, |_| on_select(day));
+                let el = dom_element! {
+                    <button type="button" {css} {on_click}>{day.day().to_string()}</button>
+                };
+                let enabled = min.is_none_or(|min| day >= min) && max.is_none_or(|max| day <= max);
+                if !enabled {
+                    el.add_attr("disabled", "disabled");
+                }
+                DomNode::from(el)
+            })
+            .collect::<Vec<_>>();
+
+        dom! {
+            <div css={&grid_css}>
+                {..weekdays}
+                {..days}
+            </div>
+        }
+    });
+
+    let container = dom_element! {
+        <div>
+            <div css={nav_css}>
+                <button type="button" css={&nav_button_css} on_click={on_prev}>"‹"</button>
+                <span>{header}</span>
+                <button type="button" css={nav_button_css} on_click={on_next}>"›"</button>
+            </div>
+            {grid}
+        </div>
+    };
+
+    DomNode::from(container)
+}
@@ -0,0 +1,176 @@
+use std::rc::Rc;
+use vertigo::{
+    AttrGroup, Computed, Css, DomNode, Value, bind, bind_rc, component, css, dom, get_driver,
+    transaction,
+};
+
+use crate::{
+    Popup, PopupParams,
+    date::{Date, DateFormat, DateTime},
+    theme::var,
+};
+
+mod calendar;
+pub use calendar::{Calendar, CalendarParams};
+
+#[derive(Clone)]
+pub struct DatePickerParams {
+    pub calendar: CalendarParams,
+    pub popup_css: Css,
+    pub button_label: Rc<String>,
+}
+
+impl Default for DatePickerParams {
+    fn default() -> Self {
+        Self {
+            calendar: Default::default(),
+            popup_css: css! {"
+                background: {var::BACKGROUND};
+                border: 1px solid;
+                border-color: {var::BORDER};
+                border-radius: {var::RADIUS_SM};
+                padding: 5px;
+                width: 220px;
+            "},
+            button_label: Rc::new("📅".to_string()),
+        }
+    }
+}
+
+impl DatePickerParams {
+    pub fn format(&self) -> &DateFormat {
+        &self.calendar.format
+    }
+}
+
+/// Text input for a date (in format from params) with a [Calendar] in a [Popup].
+///
+/// Text that can't be parsed is replaced back with current value when input loses focus.
+///
+/// Use `i` attribute group to pass anything to underlying input element.
+#[component]
+pub fn DatePicker(value: Value<Option<Date>>, params: DatePickerParams, i: AttrGroup) {
+    let format = params.format().clone();
+    let input = parsed_input(
+        &value,
+        bind!(format, |date: &Date| format.format_date(date)),
+        move |text| format.parse_date(text),
+        i,
+    );
+
+    picker(
+        input,
+        value.to_computed(),
+        Rc::new(move |date| value.set(Some(date))),
+        params,
+    )
+}
+
+/// Text input for a date with time (in format from params) with a [Calendar] in a [Popup].
+///
+/// Picking a day from calendar keeps the time.
+///
+/// Use `i` attribute group to pass anything to underlying input element.
+#[component]
+pub fn DateTimePicker(value: Value<Option<DateTime>>, params: DatePickerParams, i: AttrGroup) {
+    let format = params.format().clone();
+    let input = parsed_input(
+        &value,
+        bind!(format, |date_time: &DateTime| format
+            .format_date_time(date_time)),
+        move |text| format.parse_date_time(text),
+        i,
+    );
+
+    let selected = value.map(|date_time| date_time.map(|date_time| date_time.date()));
+    let on_select = Rc::new(move |date| {
+        value.change(|date_time| {
+            let time = date_time
+                .map(|date_time| date_time.time())
+                .unwrap_or_default();
+            *date_time = Some(DateTime::new(date, time));
+        })
+    });
+
+    picker(input, selected, on_select, params)
+}
+
+/// Current day in browser's time zone.
+pub fn today() -> Date {
+    let driver = get_driver();
+    let seconds = (driver.utc_now() / 1000) as i64 + i64::from(driver.timezone_offset());
+    Date::from_days_since_epoch(seconds.div_euclid(86400))
+}
+
+/// Text input editing an optional value through provided format and parse functions.
+///
+/// The value is updated when input loses focus, empty text clears the value.
+pub(crate) fn parsed_input<T: Clone + PartialEq + 'static>(
+    value: &Value<Option<T>>,
+    format: impl Fn(&T) -> String + 'static,
+    parse: impl Fn(&str) -> Option<T> + 'static,
+    i: AttrGroup,
+) -> DomNode {
+    let format = Rc::new(format);
+    // Text being edited, otherwise the value is shown
+    let edited = Value::<Option<String>>::default();
+
+    let text = Computed::from(bind!(value, edited, format, |ctx| {
+        edited.get(ctx).unwrap_or_else(|| {
+            value
+                .get(ctx)
+                .as_ref()
+                .map(|v| format(v))
+                .unwrap_or_default()
+        })
+    }));
+
+    let on_input = bind!(edited, |new_text: String| edited.set(Some(new_text)));
+
+    let on_change = bind!(value, edited, |new_text: String| {
+        transaction(|_| {
+            if new_text.trim().is_empty() {
+                value.set(None);
+            } else if let Some(new_value) = parse(&new_text) {
+                value.set(Some(new_value));
+            }
+            // Show formatted value (or restore text of current value if invalid)
+            edited.set(None);
+        })
+    });
+
+    dom! {
+        <input type="text" value={text} {on_input} {on_change} {..i} />
+    }
+}
+
+fn picker(
+    input: DomNode,
+    selected: Computed<Option<Date>>,
+    on_select: Rc<dyn Fn(Date)>,
+    params: DatePickerParams,
+) -> DomNode {
+    let open = Value::new(false);
+
+    let on_select = bind_rc!(open, |date: Date| {
+        on_select(date);
+        open.set(false);
+    });
+    let on_toggle = bind!(open, |_| open.change(|open| *open = !*open));
+
+    let calendar = dom! {
+        <Calendar {selected} {on_select} params={params.calendar} />
+    };
+
+    dom! {
+        <div css={css! {"display: flex; gap: 2px;"}}>
+            {input}
+            <button type="button" on_click={on_toggle}>{params.button_label}</button>
+            <Popup
+                visible={open.to_computed()}
+                content={calendar}
+                params={PopupParams { css: params.popup_css }}
+            />
+        </div>
+    }
+}
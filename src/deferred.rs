@@ -0,0 +1,38 @@
+use std::cell::RefCell;
+use vertigo::spawn;
+
+type Action = Box<dyn FnOnce()>;
+
+thread_local! {
+    static QUEUE: RefCell<Vec<Action>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Runs action in a spawned task, after the current transaction ends.
+///
+/// Source values can't be changed while the dependency graph is being refreshed,
+/// so `Computed::subscribe` callbacks use this to update other values.
+/// Actions queued before the task starts are run together, in order.
+pub(crate) fn defer(action: impl FnOnce() + 'static) {
+    let first = QUEUE.with_borrow_mut(|queue| {
+        queue.push(Box::new(action));
+        queue.len() == 1
+    });
+    if first {
+        spawn(async { run_deferred() });
+    }
+}
+
+/// Runs queued actions, including these queued by the actions themselves.
+///
+/// Spawned tasks are not executed outside of the browser, so tests call it directly.
+pub(crate) fn run_deferred() {
+    loop {
+        let actions = QUEUE.with_borrow_mut(std::mem::take);
+        if actions.is_empty() {
+            break;
+        }
+        for action in actions {
+            action();
+        }
+    }
+}
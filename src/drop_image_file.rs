@@ -5,6 +5,8 @@ use vertigo::{
     css, dom,
 };
 
+use crate::locale;
+
 /// Box that allows to accept image files on it, connected to `Value<Option<DropFileItem>>`.
 #[component]
 pub fn DropImageFile(
@@ -185,11 +187,12 @@ pub struct DropImageFileParams {
 
 impl Default for DropImageFileParams {
     fn default() -> Self {
+        let locale = locale();
         Self {
             callback: None,
-            revert_label: "Revert".to_string(),
-            cancel_label: "Cancel".to_string(),
-            no_image_text: "No image".to_string(),
+            revert_label: locale.revert.clone(),
+            cancel_label: locale.cancel.clone(),
+            no_image_text: locale.no_image.clone(),
             dropzone_css: css! {"
                 width: 400px;
                 height: 400px;
@@ -206,7 +209,7 @@ impl Default for DropImageFileParams {
                 max-height: 320px;
                 object-fit: contain;
             "},
-            select_label: "Select file...".to_string(),
+            select_label: locale.select_file.clone(),
             accept: "image/*".to_string(),
         }
     }
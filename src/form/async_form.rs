@@ -0,0 +1,169 @@
+use std::{cell::Cell, rc::Rc};
+use vertigo::{AttrGroup, Computed, component, get_driver, spawn, transaction};
+
+use super::{Form, FormData, FormExport, FormFuture, FormParams, ModelForm, Operation};
+
+/// Asynchronous submit function, resolving to an error message if saving failed.
+pub type AsyncSubmitFunc<T> = Rc<dyn Fn(T) -> FormFuture<Result<(), String>>>;
+
+/// [ModelForm] with asynchronous `on_submit`, driving [FormParams::operation] by itself.
+///
+/// See [AsyncForm] for details.
+#[component]
+pub fn AsyncModelForm<T: Clone + PartialEq>(
+    model: Computed<T>,
+    on_submit: AsyncSubmitFunc<T>,
+    params: FormParams<T>,
+    f: AttrGroup,
+    s: AttrGroup,
+) where
+    FormData: From<T>,
+    T: From<FormExport> + 'static,
+{
+    let (on_submit, params) = drive_operation(on_submit, params);
+
+    let mut form_component = ModelForm {
+        model,
+        on_submit,
+        params,
+    }
+    .into_component();
+
+    form_component.f = f;
+    form_component.s = s;
+
+    form_component.mount()
+}
+
+/// [Form] with asynchronous `on_submit`, driving [FormParams::operation] by itself.
+///
+/// The operation is `Saving` while the future is pending (further submits are ignored), then becomes
+/// `Success` or `Error` with returned message. `Success` is cleared after [FormParams::success_clear_delay].
+/// If `operation` is not provided in params, the form uses its own.
+///
+/// ```rust
+/// use std::rc::Rc;
+/// use vertigo::dom;
+/// use vertigo_forms::form::{
+///     AsyncForm, AsyncSubmitFunc, DataSection, FormData, FormExport, FormParams,
+/// };
+///
+/// let form_data = Rc::new(
+///     FormData::default().with(DataSection::with_string_field("Name", "name", "")),
+/// );
+///
+/// let on_submit: AsyncSubmitFunc<FormExport> = Rc::new(|export: FormExport| {
+///     Box::pin(async move {
+///         if export.get_string("name") == "admin" {
+///             Err("Name is taken".to_string())
+///         } else {
+///             Ok(())
+///         }
+///     })
+/// });
+///
+/// dom! {
+///     <AsyncForm {form_data} {on_submit} params={FormParams::<FormExport>::default()} />
+/// };
+/// ```
+#[component]
+pub fn AsyncForm<T>(
+    form_data: Rc<FormData>,
+    on_submit: AsyncSubmitFunc<FormExport>,
+    params: FormParams<T>,
+    f: AttrGroup,
+    s: AttrGroup,
+) where
+    T: From<FormExport> + 'static,
+{
+    let (on_submit, params) = drive_operation(on_submit, params);
+
+    let mut form_component = Form {
+        form_data,
+        on_submit,
+        params,
+    }
+    .into_component();
+
+    form_component.f = f;
+    form_component.s = s;
+
+    form_component.mount()
+}
+
+/// Wraps asynchronous submit into a synchronous one, which reports progress in operation of params.
+fn drive_operation<A: 'static, T>(
+    on_submit: AsyncSubmitFunc<A>,
+    mut params: FormParams<T>,
+) -> (Rc<dyn Fn(A)>, FormParams<T>) {
+    let operation = params
+        .operation
+        .get_or_insert_with(Default::default)
+        .clone();
+    let clear_delay = params.success_clear_delay;
+    // Future of previous submit is not resolved yet
+    let pending = Rc::new(Cell::new(false));
+    // Lets a delayed clear know that another submit happened in the meantime
+    let submit_id = Rc::new(Cell::new(0u64));
+
+    let on_submit = Rc::new(move |value: A| {
+        if pending.replace(true) {
+            return;
+        }
+        let id = submit_id.get() + 1;
+        submit_id.set(id);
+        operation.set(Operation::Saving);
+        let future = on_submit(value);
+        let operation = operation.clone();
+        let pending = pending.clone();
+        let submit_id = submit_id.clone();
+        spawn(async move {
+            let result = future.await;
+            pending.set(false);
+            match result {
+                Ok(()) => {
+                    operation.set(Operation::Success);
+                    if let Some(delay) = clear_delay {
+                        get_driver().sleep(delay).await;
+                        let still_success =
+                            transaction(|ctx| operation.get(ctx) == Operation::Success);
+                        if submit_id.get() == id && still_success {
+                            operation.set(Operation::None);
+                        }
+                    }
+                }
+                Err(message) => operation.set(Operation::err(message)),
+            }
+        });
+    });
+
+    (on_submit, params)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::Cell, future::pending, rc::Rc};
+    use vertigo::transaction;
+
+    use super::{AsyncSubmitFunc, FormParams, Operation, drive_operation};
+
+    #[test]
+    fn double_submit_calls_handler_once() {
+        let calls = Rc::new(Cell::new(0));
+        let on_submit: AsyncSubmitFunc<u32> = Rc::new({
+            let calls = calls.clone();
+            move |_| {
+                calls.set(calls.get() + 1);
+                Box::pin(pending())
+            }
+        });
+        let (on_submit, params) = drive_operation(on_submit, FormParams::<()>::default());
+
+        on_submit(1);
+        on_submit(2);
+
+        assert_eq!(calls.get(), 1);
+        let operation = params.operation.unwrap();
+        assert!(transaction(|ctx| operation.get(ctx) == Operation::Saving));
+    }
+}
@@ -1,9 +1,16 @@
-use std::{collections::HashMap, rc::Rc};
-use vertigo::{Computed, Context, DomNode, DropFileItem, Value};
+use std::{collections::HashMap, ops::Deref, rc::Rc};
+use vertigo::{
+    Computed, Context, Css, DomNode, DropFileItem, JsJson, JsJsonNumber, Value, css, transaction,
+};
 
-use crate::DropImageFileParams;
+use crate::{
+    DropImageFileParams, RadioGroupParams,
+    date::{Date, DateFormat, DateTime, Time},
+    locale,
+    theme::var,
+};
 
-use super::form_export::FieldExport;
+use super::{FormData, FormExport, form_export::FieldExport, schema::as_i64};
 
 /// Value of a field in form section.
 #[derive(Clone)]
@@ -16,12 +23,28 @@ pub enum DataFieldValue {
     List(ListValue),
     /// Integer (foreign key) field with labels for each integer.
     Dict(DictValue),
+    /// List or dict field rendered as radio buttons.
+    Radio(RadioValue),
     /// Array of integers (foreign key) field with labels for each integer.
     Multi(MultiValue),
+    /// Numeric field (number input).
+    Number(NumberValue),
+    /// Date field (text input with calendar).
+    Date(DateValue<Date>),
+    /// Time field (text input).
+    Time(DateValue<Time>),
+    /// Date and time field (text input with calendar).
+    DateTime(DateValue<DateTime>),
     /// Checkbox
     Bool(BoolValue),
     /// Image (bytes) field.
     Image(ImageValue),
+    /// Repeatable group of fields (sub-form), i.e. lines of an invoice.
+    Group(GroupValue),
+    /// Another form embedded under the key of this field, i.e. an address.
+    Nested(NestedValue),
+    /// Read-only text derived from values of other fields.
+    Computed(ComputedValue),
     /// Custom field
     Custom(CustomValue),
     /// Custom component without value
@@ -35,15 +58,163 @@ impl DataFieldValue {
             Self::TextArea(val) => FieldExport::String(val.value.get(ctx)),
             Self::List(val) => FieldExport::List(val.value.get(ctx)),
             Self::Dict(val) => FieldExport::Dict(val.value.get(ctx)),
+            Self::Radio(val) => val.as_select().export(ctx),
             Self::Multi(val) => {
                 FieldExport::Multi(val.value.get(ctx).iter().map(|v| v.get(ctx)).collect())
             }
+            Self::Number(val) => FieldExport::Number(val.value.get(ctx).trim().to_string()),
+            Self::Date(val) => Date::into_export(val.value.get(ctx)),
+            Self::Time(val) => Time::into_export(val.value.get(ctx)),
+            Self::DateTime(val) => DateTime::into_export(val.value.get(ctx)),
             Self::Bool(val) => FieldExport::Bool(val.value.get(ctx)),
             Self::Image(val) => FieldExport::Image((val.original_link.clone(), val.value.get(ctx))),
+            Self::Group(val) => FieldExport::Group(
+                val.value
+                    .get(ctx)
+                    .iter()
+                    .map(|entry| entry.export_in(ctx))
+                    .collect(),
+            ),
+            Self::Nested(val) => FieldExport::Nested(val.form_data.export_in(ctx)),
+            // Needs values of other fields, so it is computed by FormData
+            Self::Computed(_) => FieldExport::String("".to_string()),
             Self::Custom(val) => FieldExport::String(val.value.get(ctx)),
             Self::StaticCustom(_) => FieldExport::String("".to_string()),
         }
     }
+
+    /// Returns true if current value differs from the original one.
+    pub fn is_changed(&self, ctx: &Context) -> bool {
+        match self {
+            Self::String(val) => val.value.get(ctx) != *val.original_value,
+            Self::TextArea(val) => val.value.get(ctx) != original_or_default(&val.original_value),
+            Self::List(val) => val.value.get(ctx) != original_or_default(&val.original_value),
+            Self::Dict(val) => val.value.get(ctx) != original_or_default(&val.original_value),
+            Self::Radio(val) => val.as_select().is_changed(ctx),
+            Self::Multi(val) => {
+                val.value
+                    .get(ctx)
+                    .iter()
+                    .map(|v| v.get(ctx))
+                    .collect::<Vec<_>>()
+                    != *val.original_value
+            }
+            Self::Number(val) => val.value.get(ctx) != original_or_default(&val.original_value),
+            Self::Date(val) => val.value.get(ctx) != val.original_value,
+            Self::Time(val) => val.value.get(ctx) != val.original_value,
+            Self::DateTime(val) => val.value.get(ctx) != val.original_value,
+            Self::Bool(val) => val.value.get(ctx) != original_or_default(&val.original_value),
+            Self::Image(val) => val.value.get(ctx).is_some(),
+            Self::Group(val) => {
+                let entries = val.value.get(ctx);
+                entries != *val.original_value
+                    || entries
+                        .iter()
+                        .flat_map(|entry| entry.all_fields())
+                        .any(|field| field.value.is_changed(ctx))
+            }
+            Self::Nested(val) => val
+                .form_data
+                .all_fields()
+                .any(|field| field.value.is_changed(ctx)),
+            Self::Computed(_) => false,
+            Self::Custom(val) => val.value.get(ctx) != original_or_default(&val.original_value),
+            Self::StaticCustom(_) => false,
+        }
+    }
+
+    /// Sets current value back to the original one.
+    pub fn revert(&self) {
+        match self {
+            Self::String(val) => val.value.set(val.original_value.to_string()),
+            Self::TextArea(val) => val.value.set(original_or_default(&val.original_value)),
+            Self::List(val) => val.value.set(original_or_default(&val.original_value)),
+            Self::Dict(val) => val.value.set(original_or_default(&val.original_value)),
+            Self::Radio(val) => val.as_select().revert(),
+            Self::Multi(val) => val
+                .value
+                .set(val.original_value.iter().cloned().map(Value::new).collect()),
+            Self::Number(val) => val.value.set(original_or_default(&val.original_value)),
+            Self::Date(val) => val.value.set(val.original_value),
+            Self::Time(val) => val.value.set(val.original_value),
+            Self::DateTime(val) => val.value.set(val.original_value),
+            Self::Bool(val) => val.value.set(original_or_default(&val.original_value)),
+            Self::Image(val) => val.value.set(None),
+            Self::Group(val) => {
+                for entry in val.original_value.iter() {
+                    entry.reset();
+                }
+                val.value.set(val.original_value.to_vec());
+            }
+            Self::Nested(val) => val.form_data.reset(),
+            Self::Computed(_) => {}
+            Self::Custom(val) => val.value.set(original_or_default(&val.original_value)),
+            Self::StaticCustom(_) => {}
+        }
+    }
+
+    /// Sets current value from JSON produced by [FieldExport::to_json], original value is kept.
+    ///
+    /// Values of unexpected type are ignored, as well as images, computed and static fields.
+    /// Dict and multiselect fields accept only integral numbers, as options are keyed by integers.
+    pub fn load_json(&self, json: &JsJson) {
+        let string = match json {
+            JsJson::String(val) => Some(val.clone()),
+            JsJson::Number(JsJsonNumber(val)) => Some(val.to_string()),
+            _ => None,
+        };
+        let number = as_i64(json);
+        match self {
+            Self::String(val) => string.into_iter().for_each(|s| val.value.set(s)),
+            Self::TextArea(val) => string.into_iter().for_each(|s| val.value.set(s)),
+            Self::List(val) => string.into_iter().for_each(|s| val.value.set(s)),
+            Self::Dict(val) => number.into_iter().for_each(|n| val.value.set(n)),
+            Self::Radio(val) => val.as_select().load_json(json),
+            Self::Multi(val) => {
+                if let JsJson::List(items) = json {
+                    val.value.set(
+                        items
+                            .iter()
+                            .filter_map(|item| as_i64(item).map(Value::new))
+                            .collect(),
+                    )
+                }
+            }
+            Self::Number(val) => match json {
+                JsJson::Null => val.value.set(String::new()),
+                _ => string.into_iter().for_each(|s| val.value.set(s)),
+            },
+            Self::Date(val) => val.value.set(string.and_then(|s| s.parse().ok())),
+            Self::Time(val) => val.value.set(string.and_then(|s| s.parse().ok())),
+            Self::DateTime(val) => val.value.set(string.and_then(|s| s.parse().ok())),
+            Self::Bool(val) => match json {
+                JsJson::True => val.value.set(true),
+                JsJson::False => val.value.set(false),
+                _ => {}
+            },
+            Self::Group(val) => {
+                if let JsJson::List(items) = json {
+                    // Existing entries are reused, so their identity is kept where possible
+                    let mut entries = transaction(|ctx| val.value.get(ctx));
+                    entries.truncate(items.len());
+                    while entries.len() < items.len() {
+                        entries.push(GroupEntry::new((val.template)()));
+                    }
+                    for (entry, item) in entries.iter().zip(items) {
+                        entry.load_json(item);
+                    }
+                    val.value.set(entries);
+                }
+            }
+            Self::Nested(val) => val.form_data.load_json(json),
+            Self::Image(_) | Self::Computed(_) | Self::StaticCustom(_) => {}
+            Self::Custom(val) => string.into_iter().for_each(|s| val.value.set(s)),
+        }
+    }
+}
+
+fn original_or_default<T: Clone + Default>(original: &Option<Rc<T>>) -> T {
+    original.as_deref().cloned().unwrap_or_default()
 }
 
 #[derive(Clone)]
@@ -74,6 +245,22 @@ pub struct DictValue {
     pub options: Computed<Vec<(i64, String)>>,
 }
 
+#[derive(Clone)]
+pub enum RadioValue {
+    List(ListValue, RadioGroupParams),
+    Dict(DictValue, RadioGroupParams),
+}
+
+impl RadioValue {
+    /// Same field rendered as a dropdown.
+    pub fn as_select(&self) -> DataFieldValue {
+        match self {
+            Self::List(val, _) => DataFieldValue::List(val.clone()),
+            Self::Dict(val, _) => DataFieldValue::Dict(val.clone()),
+        }
+    }
+}
+
 #[derive(Clone)]
 pub struct MultiValue {
     pub value: Value<Vec<Value<i64>>>,
@@ -82,6 +269,174 @@ pub struct MultiValue {
     pub add_label: Rc<String>,
 }
 
+/// Number is kept as entered by user and parsed on export,
+/// see [FormExport::get_number](super::FormExport::get_number).
+#[derive(Clone)]
+pub struct NumberValue {
+    pub value: Value<String>,
+    pub original_value: Option<Rc<String>>,
+    pub params: NumberParams,
+}
+
+/// Constraints of a [NumberValue], rendered as attributes of number input.
+#[derive(Clone, Default)]
+pub struct NumberParams {
+    pub min: Option<f64>,
+    pub max: Option<f64>,
+    /// Defaults to 1 for integers and any for decimals
+    pub step: Option<f64>,
+    pub mode: NumberMode,
+}
+
+impl NumberParams {
+    pub fn integer() -> Self {
+        Self::default()
+    }
+
+    pub fn decimal() -> Self {
+        Self {
+            mode: NumberMode::Decimal,
+            ..Default::default()
+        }
+    }
+
+    pub fn min(mut self, min: f64) -> Self {
+        self.min = Some(min);
+        self
+    }
+
+    pub fn max(mut self, max: f64) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    pub fn step(mut self, step: f64) -> Self {
+        self.step = Some(step);
+        self
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum NumberMode {
+    #[default]
+    Integer,
+    Decimal,
+}
+
+#[derive(Clone)]
+pub struct DateValue<T: DateFieldKind> {
+    pub value: Value<Option<T>>,
+    pub original_value: Option<T>,
+    pub params: DateParams<T>,
+}
+
+/// Constraints and display format of a [DateValue].
+#[derive(Clone)]
+pub struct DateParams<T> {
+    pub min: Option<T>,
+    pub max: Option<T>,
+    pub format: DateFormat,
+}
+
+impl<T> Default for DateParams<T> {
+    fn default() -> Self {
+        Self {
+            min: None,
+            max: None,
+            format: DateFormat::default(),
+        }
+    }
+}
+
+impl<T> DateParams<T> {
+    pub fn min(mut self, min: T) -> Self {
+        self.min = Some(min);
+        self
+    }
+
+    pub fn max(mut self, max: T) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    pub fn format(mut self, format: DateFormat) -> Self {
+        self.format = format;
+        self
+    }
+}
+
+/// Common interface of [Date], [Time] and [DateTime] used by date fields.
+pub trait DateFieldKind: Copy + PartialOrd + 'static {
+    fn into_export(value: Option<Self>) -> FieldExport;
+    /// Returns value if export is of matching kind and not empty.
+    fn from_export(export: &FieldExport) -> Option<Self>;
+    fn format(&self, format: &DateFormat) -> String;
+    fn parse(text: &str, format: &DateFormat) -> Option<Self>;
+}
+
+impl DateFieldKind for Date {
+    fn into_export(value: Option<Self>) -> FieldExport {
+        FieldExport::Date(value)
+    }
+
+    fn from_export(export: &FieldExport) -> Option<Self> {
+        match export {
+            FieldExport::Date(val) => *val,
+            _ => None,
+        }
+    }
+
+    fn format(&self, format: &DateFormat) -> String {
+        format.format_date(self)
+    }
+
+    fn parse(text: &str, format: &DateFormat) -> Option<Self> {
+        format.parse_date(text)
+    }
+}
+
+impl DateFieldKind for Time {
+    fn into_export(value: Option<Self>) -> FieldExport {
+        FieldExport::Time(value)
+    }
+
+    fn from_export(export: &FieldExport) -> Option<Self> {
+        match export {
+            FieldExport::Time(val) => *val,
+            _ => None,
+        }
+    }
+
+    fn format(&self, format: &DateFormat) -> String {
+        format.format_time(self)
+    }
+
+    fn parse(text: &str, format: &DateFormat) -> Option<Self> {
+        format.parse_time(text)
+    }
+}
+
+impl DateFieldKind for DateTime {
+    fn into_export(value: Option<Self>) -> FieldExport {
+        FieldExport::DateTime(value)
+    }
+
+    fn from_export(export: &FieldExport) -> Option<Self> {
+        match export {
+            FieldExport::DateTime(val) => *val,
+            _ => None,
+        }
+    }
+
+    fn format(&self, format: &DateFormat) -> String {
+        format.format_date_time(self)
+    }
+
+    fn parse(text: &str, format: &DateFormat) -> Option<Self> {
+        format.parse_date_time(text)
+    }
+}
+
 #[derive(Clone)]
 pub struct BoolValue {
     pub value: Value<bool>,
@@ -95,6 +450,122 @@ pub struct ImageValue {
     pub component_params: Option<DropImageFileParams>,
 }
 
+/// Entries are kept as separate [FormData] instances, created using `template`.
+/// Removed and reordered entries keep state of their fields.
+#[derive(Clone)]
+pub struct GroupValue {
+    pub value: Value<Vec<GroupEntry>>,
+    pub original_value: Rc<Vec<GroupEntry>>,
+    /// Creates fields of a newly added entry
+    pub template: Rc<dyn Fn() -> FormData>,
+    pub params: GroupParams,
+}
+
+impl GroupValue {
+    /// Append new entry created from template.
+    pub fn add(&self) {
+        let entry = GroupEntry::new((self.template)());
+        self.value.change(|entries| entries.push(entry));
+    }
+
+    pub fn remove(&self, index: usize) {
+        self.value.change(|entries| {
+            if index < entries.len() {
+                entries.remove(index);
+            }
+        });
+    }
+
+    /// Move entry from one position to another, shifting entries in between.
+    pub fn move_entry(&self, from: usize, to: usize) {
+        self.value.change(|entries| {
+            if from < entries.len() && to < entries.len() {
+                let entry = entries.remove(from);
+                entries.insert(to, entry);
+            }
+        });
+    }
+}
+
+/// Single entry of a [GroupValue], entries are equal only if they are the same instance.
+#[derive(Clone)]
+pub struct GroupEntry(Rc<FormData>);
+
+impl GroupEntry {
+    pub fn new(form_data: FormData) -> Self {
+        Self(Rc::new(form_data))
+    }
+}
+
+impl PartialEq for GroupEntry {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Deref for GroupEntry {
+    type Target = FormData;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// Labels and styling of a [GroupValue].
+#[derive(Clone)]
+pub struct GroupParams {
+    pub add_label: Rc<String>,
+    /// Accessible name of the button removing an entry (it shows "×")
+    pub remove_label: Rc<String>,
+    pub move_up_label: Rc<String>,
+    pub move_down_label: Rc<String>,
+    /// Css for container of fields of every entry
+    pub entry_css: Css,
+}
+
+impl Default for GroupParams {
+    fn default() -> Self {
+        Self {
+            add_label: Rc::new(locale().add.clone()),
+            remove_label: Rc::new(locale().remove.clone()),
+            move_up_label: Rc::new("↑".to_string()),
+            move_down_label: Rc::new("↓".to_string()),
+            entry_css: css! {"
+                display: grid;
+                grid-template-columns: max-content 1fr;
+                gap: 5px;
+                flex: 1;
+                padding: 5px;
+                border: 1px solid;
+                border-color: {var::BORDER};
+                border-radius: {var::RADIUS_SM};
+            "},
+        }
+    }
+}
+
+impl GroupParams {
+    pub fn add_label(mut self, add_label: impl Into<String>) -> Self {
+        self.add_label = Rc::new(add_label.into());
+        self
+    }
+}
+
+#[derive(Clone)]
+pub struct NestedValue {
+    pub form_data: Rc<FormData>,
+    /// Css for container of nested fields (grid with two columns by default)
+    pub css: Option<Css>,
+}
+
+/// Value is computed from export of other fields of the same form (not including other computed fields).
+#[derive(Clone)]
+pub struct ComputedValue {
+    pub compute: Rc<dyn Fn(&FormExport) -> String>,
+    /// Include computed value in [FormExport] (as [FieldExport::String])
+    pub exported: bool,
+}
+
 #[derive(Clone)]
 pub struct CustomValue {
     pub value: Value<String>,
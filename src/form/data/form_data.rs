@@ -1,9 +1,20 @@
-use std::{collections::HashMap, rc::Rc};
-use vertigo::{Computed, Css, DomElement, Value, transaction};
+use regex_lite::Regex;
+use std::{collections::HashMap, fmt::Display, ops::RangeInclusive, rc::Rc, str::FromStr};
+use vertigo::{Computed, Context, Css, DomElement, DomNode, JsJson, Value, transaction};
+
+use crate::{
+    RadioGroupParams, ValidationErrors,
+    date::{Date, DateTime, Time},
+};
 
 use super::{
-    DataFieldValue, FormExport,
-    data_field::{BoolValue, DictValue, ImageValue, ListValue, MultiValue, StringValue},
+    AsyncValidator, ComputedValue, DataFieldValue, DateFieldKind, DateParams, DateValue,
+    FieldExport, FormExport, GroupEntry, GroupParams, GroupValue, NestedValue, NumberParams,
+    RadioValue, Validator,
+    data_field::{
+        BoolValue, DictValue, ImageValue, ListValue, MultiValue, NumberValue, StringValue,
+        TextAreaValue,
+    },
 };
 
 /// Used to define structure of a [Form](super::Form).
@@ -42,13 +53,22 @@ pub struct FormData {
     pub tabs: Vec<(String, Rc<Vec<DataSection>>)>,
     pub top_controls: ControlsConfig,
     pub bottom_controls: ControlsConfig,
+    /// Leave fields of hidden sections (see [DataSection::visible_when]) out of export
+    pub exclude_hidden: bool,
 }
 
+/// Controls rendered above or below the form, none by default.
+///
+/// Construct it using [ControlsConfig::full] or `Default::default()` and `with_*` methods,
+/// so new controls can be added without breaking existing code.
 #[derive(Default)]
+#[non_exhaustive]
 pub struct ControlsConfig {
     pub css: Option<Css>,
     pub submit: bool,
     pub delete: bool,
+    /// Reset control is rendered only if `reset_label` is set in [FormParams](super::FormParams)
+    pub reset: bool,
 }
 
 impl ControlsConfig {
@@ -57,6 +77,7 @@ impl ControlsConfig {
             css: None,
             submit: true,
             delete: true,
+            reset: true,
         }
     }
 
@@ -64,6 +85,21 @@ impl ControlsConfig {
         self.css = Some(css);
         self
     }
+
+    pub fn with_submit(mut self, submit: bool) -> Self {
+        self.submit = submit;
+        self
+    }
+
+    pub fn with_delete(mut self, delete: bool) -> Self {
+        self.delete = delete;
+        self
+    }
+
+    pub fn with_reset(mut self, reset: bool) -> Self {
+        self.reset = reset;
+        self
+    }
 }
 
 impl FormData {
@@ -99,23 +135,255 @@ impl FormData {
         self
     }
 
+    /// Leave fields of hidden sections (see [DataSection::visible_when]) out of export.
+    ///
+    /// Such fields are [excluded](FormExport::exclude) from export, so strict mode
+    /// (see [FormParams::strict_export](super::super::FormParams::strict_export))
+    /// doesn't report them as missing.
+    ///
+    /// ```rust
+    /// use vertigo_forms::form::{DataSection, FormData, FormExport};
+    ///
+    /// struct Account {
+    ///     name: String,
+    ///     company: String,
+    /// }
+    ///
+    /// impl From<FormExport> for Account {
+    ///     fn from(export: FormExport) -> Self {
+    ///         Self {
+    ///             name: export.get_string("name"),
+    ///             company: export.get_string("company"),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let form_data = FormData::default()
+    ///     .with(DataSection::with_string_field("Name", "name", "Alice"))
+    ///     .with(
+    ///         DataSection::with_string_field("Company name", "company", "")
+    ///             .visible_when(|values| values.get_string("name").is_empty()),
+    ///     )
+    ///     .exclude_hidden();
+    ///
+    /// let export = form_data.export().strict();
+    /// let account = Account::from(export.clone());
+    /// assert_eq!(account.name, "Alice");
+    /// assert_eq!(account.company, "");
+    /// assert!(export.errors().is_empty());
+    ///
+    /// // Keys unknown to the form are still reported
+    /// export.get_string("email");
+    /// assert!(export.errors().contains_key("email"));
+    /// ```
+    pub fn exclude_hidden(mut self) -> Self {
+        self.exclude_hidden = true;
+        self
+    }
+
+    /// Iterate over all sections, those in tabs first.
+    pub fn all_sections(&self) -> impl Iterator<Item = &DataSection> {
+        self.tabs
+            .iter()
+            .flat_map(|(_, sections)| sections.iter())
+            .chain(self.sections.iter())
+    }
+
+    /// Iterate over all fields in all sections.
+    pub fn all_fields(&self) -> impl Iterator<Item = &DataField> {
+        self.all_sections()
+            .flat_map(|section| section.fields.iter())
+    }
+
     pub fn export(&self) -> FormExport {
-        let mut hash_map = HashMap::new();
-        transaction(|ctx| {
-            for (_, sections) in &self.tabs {
-                for section in sections.iter() {
-                    for field in &section.fields {
-                        hash_map.insert(field.key.clone(), field.value.export(ctx));
-                    }
-                }
+        transaction(|ctx| self.export_in(ctx))
+    }
+
+    /// Export within provided context, so it can be used in a [Computed].
+    pub fn export_in(&self, ctx: &Context) -> FormExport {
+        let values = self.export_all_in(ctx);
+        let (visible, hidden): (Vec<_>, Vec<_>) = self
+            .all_sections()
+            .partition(|section| !self.exclude_hidden || section.is_visible(&values));
+        FormExport::new(
+            visible
+                .into_iter()
+                .flat_map(|section| section.fields.iter())
+                .filter(|field| match &field.value {
+                    DataFieldValue::Computed(val) => val.exported,
+                    _ => true,
+                })
+                .filter_map(|field| Some((field.key.clone(), values.get(&field.key)?.clone())))
+                .collect(),
+        )
+        .exclude(
+            hidden
+                .into_iter()
+                .flat_map(|section| section.fields.iter())
+                .map(|field| field.key.clone()),
+        )
+    }
+
+    /// Export of all fields, including hidden and not exported computed ones,
+    /// used to evaluate section conditions.
+    pub fn export_all_in(&self, ctx: &Context) -> FormExport {
+        let (computed, regular): (Vec<_>, Vec<_>) = self
+            .all_fields()
+            .partition(|field| matches!(field.value, DataFieldValue::Computed(_)));
+        let mut map = regular
+            .into_iter()
+            .map(|field| (field.key.clone(), field.value.export(ctx)))
+            .collect::<HashMap<_, _>>();
+        let values = FormExport::new(map.clone());
+        for field in computed {
+            if let DataFieldValue::Computed(val) = &field.value {
+                map.insert(
+                    field.key.clone(),
+                    FieldExport::String((val.compute)(&values)),
+                );
             }
-            for section in &self.sections {
-                for field in &section.fields {
-                    hash_map.insert(field.key.clone(), field.value.export(ctx));
+        }
+        FormExport::new(map)
+    }
+
+    /// Iterate over sections visible for provided values.
+    fn visible_sections<'a>(
+        &'a self,
+        values: &'a FormExport,
+    ) -> impl Iterator<Item = &'a DataSection> {
+        self.all_sections()
+            .filter(move |section| section.is_visible(values))
+    }
+
+    /// Sets all fields back to their original values.
+    pub fn reset(&self) {
+        transaction(|_| {
+            for field in self.all_fields() {
+                field.value.revert();
+            }
+        })
+    }
+
+    /// Sets field with provided key back to its original value.
+    pub fn revert_field(&self, key: &str) {
+        transaction(|_| {
+            for field in self.all_fields().filter(|field| field.key == key) {
+                field.value.revert();
+            }
+        })
+    }
+
+    /// Sets current values of fields from JSON object (i.e. produced by [FormExport::to_json]),
+    /// keys missing in the object are left untouched. Original values are kept, so the form becomes dirty.
+    ///
+    /// ```rust
+    /// use vertigo::{JsJson, JsJsonNumber};
+    /// use vertigo_forms::form::{DataSection, FormData, NumberParams};
+    ///
+    /// let form_data = FormData::default()
+    ///     .with(DataSection::with_string_field("Name", "name", "Ann"))
+    ///     .with(DataSection::new("Age").add_number_field("age", Some(30), NumberParams::integer()));
+    ///
+    /// form_data.load_json(&JsJson::Object([
+    ///     ("age".to_string(), JsJson::Number(JsJsonNumber(31.))),
+    /// ].into()));
+    ///
+    /// let export = form_data.export();
+    /// assert_eq!(export.get_string("name"), "Ann");
+    /// assert_eq!(export.get_number::<u8>("age"), Some(31));
+    /// ```
+    pub fn load_json(&self, values: &JsJson) {
+        let JsJson::Object(values) = values else {
+            return;
+        };
+        transaction(|_| {
+            for field in self.all_fields() {
+                if let Some(value) = values.get(&field.key) {
+                    field.value.load_json(value);
                 }
             }
-        });
-        FormExport::new(hash_map)
+        })
+    }
+
+    /// Run validators of all fields, returns errors by field key.
+    ///
+    /// Errors of fields in [nested](DataFieldValue::Nested) forms and [group](DataFieldValue::Group) entries
+    /// are keyed by path (i.e. `address.city`, `lines.0.price`). Fields of hidden sections are not validated.
+    pub fn validate_fields(&self) -> ValidationErrors {
+        transaction(|ctx| self.validate_fields_in(ctx))
+    }
+
+    /// Same as [validate_fields](FormData::validate_fields), within provided context.
+    pub fn validate_fields_in(&self, ctx: &Context) -> ValidationErrors {
+        let values = self.export_all_in(ctx);
+        let mut errors = ValidationErrors::new();
+        for field in self
+            .visible_sections(&values)
+            .flat_map(|section| section.fields.iter())
+        {
+            field.collect_errors("", ctx, &mut errors);
+        }
+        errors
+    }
+
+    /// Returns true if error key belongs to validators of a field (see [validate_fields](FormData::validate_fields)),
+    /// as opposed to errors set by the application or on submit.
+    pub(crate) fn is_field_error_key(&self, key: &str) -> bool {
+        self.all_fields().any(|field| {
+            (field.key == key && !field.validators.is_empty())
+                || (matches!(
+                    field.value,
+                    DataFieldValue::Group(_) | DataFieldValue::Nested(_)
+                ) && key
+                    .strip_prefix(field.key.as_str())
+                    .is_some_and(|rest| rest.starts_with('.')))
+        })
+    }
+
+    /// Returns true if any of asynchronous checks is in progress.
+    pub fn is_validation_pending(&self) -> Computed<bool> {
+        let fields = self.all_fields().cloned().collect::<Vec<_>>();
+        Computed::from(move |ctx| fields.iter().any(|field| field.is_validation_pending(ctx)))
+    }
+
+    /// Returns true if any of the fields differs from its original value.
+    ///
+    /// ```rust
+    /// use vertigo::transaction;
+    /// use vertigo_forms::form::{DataFieldValue, DataSection, FormData};
+    ///
+    /// let form_data =
+    ///     FormData::default().with(DataSection::with_string_field("Name", "name", "Johann"));
+    /// let is_dirty = form_data.is_dirty();
+    /// assert!(!transaction(|ctx| is_dirty.get(ctx)));
+    ///
+    /// if let DataFieldValue::String(val) = &form_data.sections[0].fields[0].value {
+    ///     val.value.set("Hans".to_string());
+    /// }
+    /// assert!(transaction(|ctx| is_dirty.get(ctx)));
+    /// assert_eq!(
+    ///     transaction(|ctx| form_data.changed_keys().get(ctx)),
+    ///     vec!["name".to_string()]
+    /// );
+    ///
+    /// form_data.reset();
+    /// assert!(!transaction(|ctx| is_dirty.get(ctx)));
+    /// ```
+    pub fn is_dirty(&self) -> Computed<bool> {
+        let fields = self.all_fields().cloned().collect::<Vec<_>>();
+        Computed::from(move |ctx| fields.iter().any(|field| field.value.is_changed(ctx)))
+    }
+
+    /// Returns keys of fields which differ from their original values.
+    pub fn changed_keys(&self) -> Computed<Vec<String>> {
+        let fields = self.all_fields().cloned().collect::<Vec<_>>();
+        Computed::from(move |ctx| {
+            fields
+                .iter()
+                .filter(|field| field.value.is_changed(ctx))
+                .map(|field| field.key.clone())
+                .collect()
+        })
     }
 }
 
@@ -129,6 +397,9 @@ pub enum FieldsetStyle {
     Dimensions,
 }
 
+/// Condition evaluated against current values of the form (see [DataSection::visible_when]).
+pub type SectionCondition = Rc<dyn Fn(&FormExport) -> bool>;
+
 /// A section of form with label and a field (or field set).
 #[derive(Default)]
 // #[derive(Clone)]
@@ -140,6 +411,10 @@ pub struct DataSection {
     pub fieldset_style: FieldsetStyle,
     pub fieldset_css: Option<Css>,
     pub new_group: bool,
+    pub visible_when: Option<SectionCondition>,
+    pub enabled_when: Option<SectionCondition>,
+    /// Content of a popup shown when hovering an icon next to the label
+    pub tooltip: Option<Rc<dyn Fn() -> DomNode>>,
 }
 
 /// A single field in form section.
@@ -147,6 +422,109 @@ pub struct DataSection {
 pub struct DataField {
     pub key: String,
     pub value: DataFieldValue,
+    pub validators: Vec<Validator>,
+    pub async_validator: Option<AsyncValidator>,
+    /// Description of the field, rendered below the control (or in a popup)
+    pub help: Option<String>,
+    /// Placeholder of text-like controls
+    pub placeholder: Option<String>,
+}
+
+impl DataField {
+    pub fn new(key: impl Into<String>, value: DataFieldValue) -> Self {
+        Self {
+            key: key.into(),
+            value,
+            validators: vec![],
+            async_validator: None,
+            help: None,
+            placeholder: None,
+        }
+    }
+
+    /// Attach a [Validator] to this field.
+    pub fn with_validator(mut self, validator: Validator) -> Self {
+        self.validators.push(validator);
+        self
+    }
+
+    /// Attach an [AsyncValidator] to this field, it starts watching changes of the value.
+    pub fn with_async_validator(mut self, async_validator: AsyncValidator) -> Self {
+        self.async_validator = Some(async_validator.watch(&self.value));
+        self
+    }
+
+    /// Returns true if field has [Validator::required] attached.
+    pub fn is_required(&self) -> bool {
+        self.validators.iter().any(Validator::is_required)
+    }
+
+    /// Run all validators against current value, returns first error.
+    /// Includes the result of the most recent asynchronous check.
+    pub fn validate(&self, ctx: &Context) -> Option<String> {
+        let async_error = || {
+            self.async_validator
+                .as_ref()
+                .and_then(|async_validator| async_validator.error.get(ctx))
+        };
+        if self.validators.is_empty() {
+            return async_error();
+        }
+        let value = self.value.export(ctx);
+        self.validators
+            .iter()
+            .find_map(|validator| validator.validate(&value))
+            .or_else(async_error)
+    }
+
+    /// Returns true if asynchronous check (of this field or any field nested in it) is in progress.
+    pub fn is_validation_pending(&self, ctx: &Context) -> bool {
+        self.async_validator
+            .as_ref()
+            .is_some_and(|async_validator| async_validator.pending.get(ctx))
+            || {
+                let mut pending = false;
+                self.for_each_nested(ctx, |_, form_data| {
+                    pending = pending
+                        || form_data
+                            .all_fields()
+                            .any(|field| field.is_validation_pending(ctx));
+                });
+                pending
+            }
+    }
+
+    /// Validate this field and fields nested in it, inserting errors under `prefix` + key path.
+    fn collect_errors(&self, prefix: &str, ctx: &Context, errors: &mut ValidationErrors) {
+        let path = format!("{prefix}{}", self.key);
+        if let Some(error) = self.validate(ctx) {
+            errors.insert(path.clone(), error);
+        }
+        self.for_each_nested(ctx, |nested_path, form_data| {
+            let prefix = format!("{path}.{nested_path}");
+            let values = form_data.export_all_in(ctx);
+            for field in form_data
+                .visible_sections(&values)
+                .flat_map(|section| section.fields.iter())
+            {
+                field.collect_errors(&prefix, ctx, errors);
+            }
+        });
+    }
+
+    /// Calls `f` for every form nested in this field, with its path relative to this field
+    /// (i.e. `0.` for the first entry of a group).
+    fn for_each_nested(&self, ctx: &Context, mut f: impl FnMut(&str, &FormData)) {
+        match &self.value {
+            DataFieldValue::Group(val) => {
+                for (idx, entry) in val.value.get(ctx).iter().enumerate() {
+                    f(&format!("{idx}."), entry);
+                }
+            }
+            DataFieldValue::Nested(val) => f("", &val.form_data),
+            _ => {}
+        }
+    }
 }
 
 impl DataSection {
@@ -167,13 +545,13 @@ impl DataSection {
         let value = original_value.into();
         Self {
             label: label.into(),
-            fields: vec![DataField {
-                key: key.into(),
-                value: DataFieldValue::String(StringValue {
+            fields: vec![DataField::new(
+                key,
+                DataFieldValue::String(StringValue {
                     value: Value::new(value.clone()),
                     original_value: Rc::new(value),
                 }),
-            }],
+            )],
             ..Default::default()
         }
     }
@@ -188,10 +566,7 @@ impl DataSection {
     }
 
     pub fn add_field(mut self, key: impl Into<String>, value: DataFieldValue) -> Self {
-        self.fields.push(DataField {
-            key: key.into(),
-            value,
-        });
+        self.fields.push(DataField::new(key, value));
         self
     }
 
@@ -202,13 +577,13 @@ impl DataSection {
         original_value: impl Into<String>,
     ) -> Self {
         let value = original_value.into();
-        self.fields.push(DataField {
-            key: key.into(),
-            value: DataFieldValue::String(StringValue {
+        self.fields.push(DataField::new(
+            key,
+            DataFieldValue::String(StringValue {
                 value: Value::new(value.clone()),
                 original_value: Rc::new(value),
             }),
-        });
+        ));
         self
     }
 
@@ -221,6 +596,27 @@ impl DataSection {
         self.add_string_field(key, original_value.clone().unwrap_or_default())
     }
 
+    /// Add another multiline string field to form section (textarea with optional size).
+    pub fn add_textarea_field(
+        mut self,
+        key: impl Into<String>,
+        original_value: Option<impl Into<String>>,
+        rows: Option<i32>,
+        cols: Option<i32>,
+    ) -> Self {
+        let value = original_value.map(|s| s.into());
+        self.fields.push(DataField::new(
+            key,
+            DataFieldValue::TextArea(TextAreaValue {
+                value: Value::new(value.clone().unwrap_or_default()),
+                original_value: value.map(Rc::new),
+                rows,
+                cols,
+            }),
+        ));
+        self
+    }
+
     /// Add another list field to form section (dropdown with options).
     pub fn add_list_field(
         mut self,
@@ -230,14 +626,61 @@ impl DataSection {
     ) -> Self {
         let value = original_value.map(|s| s.into());
         let options = Computed::from(move |_ctx| options.clone());
-        self.fields.push(DataField {
-            key: key.into(),
-            value: DataFieldValue::List(ListValue {
+        self.fields.push(DataField::new(
+            key,
+            DataFieldValue::List(ListValue {
                 value: Value::new(value.clone().unwrap_or_default()),
                 original_value: value.map(Rc::new),
                 options,
             }),
-        });
+        ));
+        self
+    }
+
+    /// Add another list field to form section (radio buttons).
+    pub fn add_radio_field(
+        mut self,
+        key: impl Into<String>,
+        original_value: Option<impl Into<String>>,
+        options: Vec<String>,
+        params: RadioGroupParams,
+    ) -> Self {
+        let value = original_value.map(|s| s.into());
+        let options = Computed::from(move |_ctx| options.clone());
+        self.fields.push(DataField::new(
+            key,
+            DataFieldValue::Radio(RadioValue::List(
+                ListValue {
+                    value: Value::new(value.clone().unwrap_or_default()),
+                    original_value: value.map(Rc::new),
+                    options,
+                },
+                params,
+            )),
+        ));
+        self
+    }
+
+    /// Add another dict field to form section (radio buttons), value is stored as integer.
+    pub fn add_dict_radio_field(
+        mut self,
+        key: impl Into<String>,
+        original_value: Option<i64>,
+        options: Vec<(i64, String)>,
+        params: RadioGroupParams,
+    ) -> Self {
+        let options = Computed::from(move |_ctx| options.clone());
+        self.fields.push(DataField::new(
+            key,
+            DataFieldValue::Radio(RadioValue::Dict(
+                DictValue {
+                    value: Value::new(original_value.unwrap_or_default()),
+                    original_value: original_value.map(Rc::new),
+                    options,
+                },
+                params,
+            )),
+        ));
         self
     }
 
@@ -261,14 +704,14 @@ impl DataSection {
         original_value: Option<i64>,
         options: Computed<Vec<(i64, String)>>,
     ) -> Self {
-        self.fields.push(DataField {
-            key: key.into(),
-            value: DataFieldValue::Dict(DictValue {
+        self.fields.push(DataField::new(
+            key,
+            DataFieldValue::Dict(DictValue {
                 value: Value::new(original_value.unwrap_or_default()),
                 original_value: original_value.map(Rc::new),
                 options,
             }),
-        });
+        ));
         self
     }
 
@@ -280,15 +723,244 @@ impl DataSection {
         options: Computed<HashMap<i64, String>>,
         add_label: impl Into<String>,
     ) -> Self {
-        self.fields.push(DataField {
-            key: key.into(),
-            value: DataFieldValue::Multi(MultiValue {
+        self.fields.push(DataField::new(
+            key,
+            DataFieldValue::Multi(MultiValue {
                 value: Value::new(original_value.iter().cloned().map(Value::new).collect()),
                 original_value: Rc::new(original_value),
                 options,
                 add_label: Rc::new(add_label.into()),
             }),
-        });
+        ));
+        self
+    }
+
+    /// Add repeatable group of fields to form section.
+    ///
+    /// Fields of every entry are created by `template`, which gets `None` for entries added by user.
+    /// Entries are exported as [FieldExport::Group].
+    ///
+    /// ```rust
+    /// use vertigo::transaction;
+    /// use vertigo_forms::form::{DataFieldValue, DataSection, FormData, GroupParams};
+    ///
+    /// struct Line {
+    ///     name: String,
+    ///     quantity: u32,
+    /// }
+    ///
+    /// let lines = vec![Line { name: "Nails".to_string(), quantity: 100 }];
+    ///
+    /// let form_data = FormData::default().with(DataSection::new("Lines").add_group_field(
+    ///     "lines",
+    ///     &lines,
+    ///     |line: Option<&Line>| {
+    ///         FormData::default().with(
+    ///             DataSection::with_string_field("Name", "name", line.map(|l| l.name.as_str()).unwrap_or_default())
+    ///                 .add_number_field("quantity", line.map(|l| l.quantity), Default::default()),
+    ///         )
+    ///     },
+    ///     GroupParams::default(),
+    /// ));
+    ///
+    /// if let DataFieldValue::Group(group) = &form_data.sections[0].fields[0].value {
+    ///     group.add();
+    ///     group.move_entry(1, 0);
+    /// }
+    ///
+    /// let lines = form_data.export().group("lines");
+    /// assert_eq!(lines.len(), 2);
+    /// assert_eq!(lines[0].get_string("name"), "");
+    /// assert_eq!(lines[1].get_number::<u32>("quantity"), Some(100));
+    /// assert!(transaction(|ctx| form_data.is_dirty().get(ctx)));
+    ///
+    /// form_data.reset();
+    /// assert_eq!(form_data.export().group("lines").len(), 1);
+    /// ```
+    pub fn add_group_field<C: 'static>(
+        mut self,
+        key: impl Into<String>,
+        original_value: &[C],
+        template: impl Fn(Option<&C>) -> FormData + 'static,
+        params: GroupParams,
+    ) -> Self {
+        let entries = original_value
+            .iter()
+            .map(|item| GroupEntry::new(template(Some(item))))
+            .collect::<Vec<_>>();
+        self.fields.push(DataField::new(
+            key,
+            DataFieldValue::Group(GroupValue {
+                value: Value::new(entries.clone()),
+                original_value: Rc::new(entries),
+                template: Rc::new(move || template(None)),
+                params,
+            }),
+        ));
+        self
+    }
+
+    /// Add repeatable group of fields to form section, with entries created from models
+    /// (i.e. derived using [FormModel](super::super::FormModel)).
+    ///
+    /// New entries are created from `C::default()`.
+    /// Use [FormExport::group_into] to convert entries back to models.
+    pub fn add_model_group_field<C>(
+        self,
+        key: impl Into<String>,
+        original_value: &[C],
+        params: GroupParams,
+    ) -> Self
+    where
+        C: Clone + Default + 'static,
+        FormData: From<C>,
+    {
+        self.add_group_field(
+            key,
+            original_value,
+            |item: Option<&C>| FormData::from(item.cloned().unwrap_or_default()),
+            params,
+        )
+    }
+
+    /// Embed another form in this section, its fields are exported under `key`
+    /// (see [FormExport::nested]) and their validation errors are keyed by path (i.e. `address.city`).
+    ///
+    /// ```rust
+    /// use vertigo_forms::form::{DataSection, FormData};
+    ///
+    /// let address = FormData::default()
+    ///     .with(DataSection::with_string_field("City", "city", "Lisbon").required());
+    /// let form_data = FormData::default()
+    ///     .with(DataSection::with_string_field("Name", "name", "Ana"))
+    ///     .with(DataSection::new("Address").add_nested_field("address", address));
+    ///
+    /// let export = form_data.export();
+    /// assert_eq!(export.nested("address").get_string("city"), "Lisbon");
+    /// assert_eq!(export.get_string("address.city"), "Lisbon");
+    /// ```
+    pub fn add_nested_field(mut self, key: impl Into<String>, form_data: FormData) -> Self {
+        self.fields.push(DataField::new(
+            key,
+            DataFieldValue::Nested(NestedValue {
+                form_data: Rc::new(form_data),
+                css: None,
+            }),
+        ));
+        self
+    }
+
+    /// Add read-only text computed from values of other fields of the form.
+    ///
+    /// Use `exported` to include the value in [FormExport] (as a string).
+    ///
+    /// ```rust
+    /// use vertigo_forms::form::{DataSection, FormData, NumberParams};
+    ///
+    /// let form_data = FormData::default()
+    ///     .with(DataSection::new("Price").add_number_field("price", Some(100), NumberParams::decimal()))
+    ///     .with(DataSection::new("Net price").add_computed_field("net", true, |values| {
+    ///         let price = values.number_or_default::<f64>("price");
+    ///         format!("{:.2}", price / 1.23)
+    ///     }));
+    ///
+    /// assert_eq!(form_data.export().get_string("net"), "81.30");
+    /// ```
+    pub fn add_computed_field(
+        mut self,
+        key: impl Into<String>,
+        exported: bool,
+        compute: impl Fn(&FormExport) -> String + 'static,
+    ) -> Self {
+        self.fields.push(DataField::new(
+            key,
+            DataFieldValue::Computed(ComputedValue {
+                compute: Rc::new(compute),
+                exported,
+            }),
+        ));
+        self
+    }
+
+    /// Add another number field to form section (number input).
+    ///
+    /// Value is validated against [NumberParams] by the [Form](super::super::Form).
+    pub fn add_number_field(
+        mut self,
+        key: impl Into<String>,
+        original_value: Option<impl ToString>,
+        params: NumberParams,
+    ) -> Self {
+        let value = original_value.map(|n| n.to_string());
+        self.fields.push(
+            DataField::new(
+                key,
+                DataFieldValue::Number(NumberValue {
+                    value: Value::new(value.clone().unwrap_or_default()),
+                    original_value: value.map(Rc::new),
+                    params: params.clone(),
+                }),
+            )
+            .with_validator(Validator::number(&params)),
+        );
+        self
+    }
+
+    /// Add another date field to form section (text input with calendar).
+    ///
+    /// Value is validated against bounds from [DateParams] by the [Form](super::super::Form).
+    pub fn add_date_field(
+        self,
+        key: impl Into<String>,
+        original_value: Option<Date>,
+        params: DateParams<Date>,
+    ) -> Self {
+        self.add_date_kind_field(key, original_value, params, DataFieldValue::Date)
+    }
+
+    /// Add another time field to form section (text input).
+    ///
+    /// Value is validated against bounds from [DateParams] by the [Form](super::super::Form).
+    pub fn add_time_field(
+        self,
+        key: impl Into<String>,
+        original_value: Option<Time>,
+        params: DateParams<Time>,
+    ) -> Self {
+        self.add_date_kind_field(key, original_value, params, DataFieldValue::Time)
+    }
+
+    /// Add another date and time field to form section (text input with calendar).
+    ///
+    /// Value is validated against bounds from [DateParams] by the [Form](super::super::Form).
+    pub fn add_date_time_field(
+        self,
+        key: impl Into<String>,
+        original_value: Option<DateTime>,
+        params: DateParams<DateTime>,
+    ) -> Self {
+        self.add_date_kind_field(key, original_value, params, DataFieldValue::DateTime)
+    }
+
+    fn add_date_kind_field<T: DateFieldKind>(
+        mut self,
+        key: impl Into<String>,
+        original_value: Option<T>,
+        params: DateParams<T>,
+        variant: fn(DateValue<T>) -> DataFieldValue,
+    ) -> Self {
+        let validator = Validator::date_bounds(&params);
+        self.fields.push(
+            DataField::new(
+                key,
+                variant(DateValue {
+                    value: Value::new(original_value),
+                    original_value,
+                    params,
+                }),
+            )
+            .with_validator(validator),
+        );
         self
     }
 
@@ -299,13 +971,13 @@ impl DataSection {
         original_value: Option<impl Into<bool>>,
     ) -> Self {
         let value = original_value.map(|b| b.into());
-        self.fields.push(DataField {
-            key: key.into(),
-            value: DataFieldValue::Bool(BoolValue {
+        self.fields.push(DataField::new(
+            key,
+            DataFieldValue::Bool(BoolValue {
                 value: Value::new(value.unwrap_or_default()),
                 original_value: value.map(Rc::new),
             }),
-        });
+        ));
         self
     }
 
@@ -316,14 +988,14 @@ impl DataSection {
         original_value: Option<impl Into<String>>,
     ) -> Self {
         let value = original_value.map(|l| l.into());
-        self.fields.push(DataField {
-            key: key.into(),
-            value: DataFieldValue::Image(ImageValue {
+        self.fields.push(DataField::new(
+            key,
+            DataFieldValue::Image(ImageValue {
                 value: Value::new(None),
                 original_link: value.map(Rc::new),
                 component_params: None,
             }),
-        });
+        ));
         self
     }
 
@@ -344,4 +1016,154 @@ impl DataSection {
         self.new_group = true;
         self
     }
+
+    /// Show this section only when condition is met by current values of the form.
+    ///
+    /// Fields of hidden section are not validated and can be left out of export
+    /// (see [FormData::exclude_hidden]). Values are reported by keys within the same form
+    /// (for nested forms and group entries - keys of the nested form).
+    ///
+    /// ```rust
+    /// use vertigo::transaction;
+    /// use vertigo_forms::form::{DataFieldValue, DataSection, FormData};
+    ///
+    /// let form_data = FormData::default()
+    ///     .with(DataSection::new("Account type").add_static_dict_field(
+    ///         "account_type",
+    ///         Some(1),
+    ///         vec![(1, "Personal".to_string()), (2, "Business".to_string())],
+    ///     ))
+    ///     .with(
+    ///         DataSection::with_string_field("Company name", "company", "")
+    ///             .required()
+    ///             .visible_when(|values| values.dict_or_default::<i64>("account_type") == 2),
+    ///     )
+    ///     .exclude_hidden();
+    ///
+    /// assert!(form_data.export().get("company").is_none());
+    /// assert!(form_data.validate_fields().is_empty());
+    ///
+    /// if let DataFieldValue::Dict(val) = &form_data.sections[0].fields[0].value {
+    ///     val.value.set(2);
+    /// }
+    /// assert_eq!(form_data.export().get_string("company"), "");
+    /// assert!(form_data.validate_fields().contains_key("company"));
+    /// ```
+    pub fn visible_when(mut self, condition: impl Fn(&FormExport) -> bool + 'static) -> Self {
+        self.visible_when = Some(Rc::new(condition));
+        self
+    }
+
+    /// Enable controls of this section only when condition is met by current values of the form
+    /// (see [visible_when](DataSection::visible_when)).
+    pub fn enabled_when(mut self, condition: impl Fn(&FormExport) -> bool + 'static) -> Self {
+        self.enabled_when = Some(Rc::new(condition));
+        self
+    }
+
+    /// Returns true if this section should be shown for provided values.
+    pub fn is_visible(&self, values: &FormExport) -> bool {
+        self.visible_when
+            .as_ref()
+            .is_none_or(|condition| condition(values))
+    }
+
+    /// Returns true if controls of this section should be enabled for provided values.
+    pub fn is_enabled(&self, values: &FormExport) -> bool {
+        self.enabled_when
+            .as_ref()
+            .is_none_or(|condition| condition(values))
+    }
+
+    /// Show a popup with provided content next to the label of this section.
+    pub fn tooltip(mut self, render: impl Fn() -> DomNode + 'static) -> Self {
+        self.tooltip = Some(Rc::new(render));
+        self
+    }
+
+    /// Describe the most recently added field (i.e. "Used in URLs, lowercase only").
+    ///
+    /// ```rust
+    /// use vertigo_forms::form::DataSection;
+    ///
+    /// let section = DataSection::with_string_field("Slug", "slug", "")
+    ///     .help("Used in URLs, lowercase only")
+    ///     .placeholder("my-article");
+    /// assert_eq!(section.fields[0].help.as_deref(), Some("Used in URLs, lowercase only"));
+    /// assert_eq!(section.fields[0].placeholder.as_deref(), Some("my-article"));
+    /// ```
+    pub fn help(mut self, text: impl Into<String>) -> Self {
+        if let Some(field) = self.fields.last_mut() {
+            field.help = Some(text.into());
+        }
+        self
+    }
+
+    /// Set placeholder of the most recently added field, used by text, number and date controls.
+    pub fn placeholder(mut self, text: impl Into<String>) -> Self {
+        if let Some(field) = self.fields.last_mut() {
+            field.placeholder = Some(text.into());
+        }
+        self
+    }
+
+    /// Attach a [Validator] to the most recently added field.
+    pub fn validator(mut self, validator: Validator) -> Self {
+        if let Some(field) = self.fields.pop() {
+            self.fields.push(field.with_validator(validator));
+        }
+        self
+    }
+
+    /// The most recently added field can not be empty (see [Validator::required]).
+    pub fn required(self) -> Self {
+        self.validator(Validator::required())
+    }
+
+    /// The most recently added field must be at least `min` characters long (see [Validator::min_len]).
+    pub fn min_len(self, min: usize) -> Self {
+        self.validator(Validator::min_len(min))
+    }
+
+    /// The most recently added field must be at most `max` characters long (see [Validator::max_len]).
+    pub fn max_len(self, max: usize) -> Self {
+        self.validator(Validator::max_len(max))
+    }
+
+    /// The most recently added field must match provided regex (see [Validator::pattern]).
+    pub fn pattern(self, regex: Regex) -> Self {
+        self.validator(Validator::pattern(regex))
+    }
+
+    /// The most recently added field must be within provided range (see [Validator::range]).
+    pub fn range<T>(self, range: RangeInclusive<T>) -> Self
+    where
+        T: PartialOrd + FromStr + Display + 'static,
+    {
+        self.validator(Validator::range(range))
+    }
+
+    /// Validate the most recently added field using custom function, which returns error message if invalid.
+    pub fn custom(self, validate: impl Fn(&FieldExport) -> Option<String> + 'static) -> Self {
+        self.validator(Validator::new(validate))
+    }
+
+    /// Validate the most recently added field using async function (see [AsyncValidator]).
+    pub fn validate_async<F, Fut>(mut self, validate: F) -> Self
+    where
+        F: Fn(FieldExport) -> Fut + 'static,
+        Fut: Future<Output = Option<String>> + 'static,
+    {
+        if let Some(field) = self.fields.pop() {
+            self.fields
+                .push(field.with_async_validator(AsyncValidator::new(validate)));
+        }
+        self
+    }
+
+    /// Returns true if any of the fields in this section differs from its original value.
+    pub fn is_dirty(&self) -> Computed<bool> {
+        let fields = self.fields.clone();
+        Computed::from(move |ctx| fields.iter().any(|field| field.value.is_changed(ctx)))
+    }
 }
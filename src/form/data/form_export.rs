@@ -1,28 +1,262 @@
-use std::{collections::HashMap, rc::Rc};
+use std::{
+    any::type_name,
+    cell::RefCell,
+    collections::{HashMap, HashSet},
+    fmt::Display,
+    rc::Rc,
+    str::FromStr,
+};
 use vertigo::DropFileItem;
 
-use crate::{image_as_uri, nonify};
+use crate::{
+    ValidationErrors,
+    date::{Date, DateTime, Time},
+    image_as_uri, nonify,
+};
 
+#[derive(Clone, Debug, PartialEq)]
 pub enum FieldExport {
     Bool(bool),
     String(String),
     List(String),
     Dict(i64),
     Multi(Vec<i64>),
+    /// Number as entered by user
+    Number(String),
+    Date(Option<Date>),
+    Time(Option<Time>),
+    DateTime(Option<DateTime>),
     Image((Option<Rc<String>>, Option<DropFileItem>)),
+    /// Entries of a group field
+    Group(Vec<FormExport>),
+    /// Fields of a nested form
+    Nested(FormExport),
 }
 
+impl FieldExport {
+    /// Name of the variant, used in error messages.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::Bool(_) => "bool",
+            Self::String(_) => "string",
+            Self::List(_) => "list",
+            Self::Dict(_) => "dict",
+            Self::Multi(_) => "multi",
+            Self::Number(_) => "number",
+            Self::Date(_) => "date",
+            Self::Time(_) => "time",
+            Self::DateTime(_) => "datetime",
+            Self::Image(_) => "image",
+            Self::Group(_) => "group",
+            Self::Nested(_) => "nested",
+        }
+    }
+}
+
+/// Error returned by fallible (`try_*`) getters of [FormExport].
+#[derive(Clone, Debug, PartialEq)]
+pub enum FieldExportError {
+    /// There is no field with such key in the export.
+    MissingKey(String),
+    /// Field exists but holds a different [FieldExport] variant.
+    WrongKind {
+        key: String,
+        expected: &'static str,
+        found: &'static str,
+    },
+    /// Field value can't be converted to requested type.
+    InvalidValue { key: String, message: String },
+}
+
+impl FieldExportError {
+    /// Key of the field this error refers to.
+    pub fn key(&self) -> &str {
+        match self {
+            Self::MissingKey(key) => key,
+            Self::WrongKind { key, .. } => key,
+            Self::InvalidValue { key, .. } => key,
+        }
+    }
+}
+
+impl Display for FieldExportError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MissingKey(key) => write!(f, "Missing field \"{key}\""),
+            Self::WrongKind {
+                key,
+                expected,
+                found,
+            } => write!(f, "Field \"{key}\" is {found}, expected {expected}"),
+            Self::InvalidValue { message, .. } => write!(f, "{message}"),
+        }
+    }
+}
+
+impl std::error::Error for FieldExportError {}
+
 /// After form is submitted, it generates an export from every field. This can be used to construct a new model.
+///
+/// Getters like [get_string](FormExport::get_string) return default value if the key is missing
+/// or holds a different kind of field, while `try_*` counterparts return [FieldExportError].
+///
+/// In [strict](FormExport::strict) mode the lenient getters still return defaults,
+/// but the errors are also collected and can be retrieved using [errors](FormExport::errors).
+/// Keys [excluded](FormExport::exclude) from export (i.e. fields of hidden sections) are not reported as missing.
+///
+/// Fields of nested forms and group entries can be reached by path (i.e. `address.city`)
+/// or through sub-exports returned by [nested](FormExport::nested) and [group](FormExport::group).
+///
+/// ```rust
+/// use std::collections::HashMap;
+/// use vertigo_forms::form::{FieldExport, FieldExportError, FormExport};
+///
+/// let export = FormExport::new(HashMap::from([
+///     ("role".to_string(), FieldExport::Dict(2)),
+///     ("age".to_string(), FieldExport::Number("42".to_string())),
+///     ("price".to_string(), FieldExport::Number("abc".to_string())),
+///     ("address".to_string(), FieldExport::Nested(FormExport::new(HashMap::from([
+///         ("city".to_string(), FieldExport::String("Oslo".to_string())),
+///     ])))),
+/// ]))
+/// .strict();
+///
+/// assert_eq!(export.try_dict::<i64>("role"), Ok(2));
+/// assert_eq!(export.get_number::<u8>("age"), Some(42));
+/// assert_eq!(export.get_number::<f64>("price"), None);
+/// assert_eq!(export.errors().get("price").unwrap(), "\"abc\" is not a valid f64");
+/// assert_eq!(
+///     export.try_get_bool("role"),
+///     Err(FieldExportError::WrongKind { key: "role".to_string(), expected: "bool", found: "dict" })
+/// );
+///
+/// assert_eq!(export.get_string("name"), "");
+/// assert_eq!(export.errors().get("name").unwrap(), "Missing field \"name\"");
+///
+/// let address = export.nested("address");
+/// assert_eq!(address.get_string("city"), "Oslo");
+/// assert!(!address.get_bool("city"));
+/// assert_eq!(export.errors().get("address.city").unwrap(), "Field \"city\" is string, expected bool");
+/// ```
 #[derive(Clone)]
-pub struct FormExport(Rc<HashMap<String, FieldExport>>);
+pub struct FormExport {
+    map: Rc<HashMap<String, FieldExport>>,
+    /// Keys left out of export on purpose
+    excluded: Rc<HashSet<String>>,
+    errors: Option<Rc<RefCell<ValidationErrors>>>,
+    /// Path of this export in parent export (i.e. `lines.0.`), used as prefix of error keys
+    prefix: Rc<String>,
+}
 
 impl FormExport {
     pub fn new(map: HashMap<String, FieldExport>) -> Self {
-        Self(Rc::new(map))
+        Self {
+            map: Rc::new(map),
+            excluded: Default::default(),
+            errors: None,
+            prefix: Default::default(),
+        }
     }
 
+    /// Mark keys as left out of export on purpose, so strict mode doesn't report them as missing.
+    pub fn exclude(self, keys: impl IntoIterator<Item = String>) -> Self {
+        let mut excluded = (*self.excluded).clone();
+        excluded.extend(keys);
+        Self {
+            excluded: Rc::new(excluded),
+            ..self
+        }
+    }
+
+    /// Returns true if field (also by path) was left out of export on purpose.
+    pub fn is_excluded(&self, key: &str) -> bool {
+        self.excluded.contains(key)
+            || key
+                .split_once('.')
+                .is_some_and(|(head, rest)| match self.map.get(head) {
+                    Some(FieldExport::Nested(nested)) => nested.is_excluded(rest),
+                    Some(FieldExport::Group(entries)) => rest
+                        .split_once('.')
+                        .and_then(|(idx, rest)| {
+                            Some(entries.get(idx.parse::<usize>().ok()?)?.is_excluded(rest))
+                        })
+                        .unwrap_or_default(),
+                    _ => false,
+                })
+    }
+
+    /// Switch to strict mode, where lenient getters collect errors.
+    pub fn strict(self) -> Self {
+        Self {
+            errors: Some(Default::default()),
+            ..self
+        }
+    }
+
+    /// Errors collected by lenient getters in strict mode.
+    pub fn errors(&self) -> ValidationErrors {
+        self.errors
+            .as_ref()
+            .map(|errors| errors.borrow().clone())
+            .unwrap_or_default()
+    }
+
+    /// Iterate over all fields (of this level only).
+    pub fn iter(&self) -> impl Iterator<Item = (&String, &FieldExport)> {
+        self.map.iter()
+    }
+
+    /// Get field by key or by path to a field in nested form or group entry
+    /// (i.e. `address.city`, `lines.0.price`).
     pub fn get<'a>(&'a self, key: &str) -> Option<&'a FieldExport> {
-        self.0.get(key)
+        self.map.get(key).or_else(|| {
+            let (head, rest) = key.split_once('.')?;
+            match self.map.get(head)? {
+                FieldExport::Nested(nested) => nested.get(rest),
+                FieldExport::Group(entries) => {
+                    let (idx, rest) = rest.split_once('.')?;
+                    entries.get(idx.parse::<usize>().ok()?)?.get(rest)
+                }
+                _ => None,
+            }
+        })
+    }
+
+    /// Get field or [FieldExportError::MissingKey].
+    pub fn try_get<'a>(&'a self, key: &str) -> Result<&'a FieldExport, FieldExportError> {
+        self.get(key)
+            .ok_or_else(|| FieldExportError::MissingKey(key.to_string()))
+    }
+
+    fn wrong_kind(key: &str, expected: &'static str, found: &FieldExport) -> FieldExportError {
+        FieldExportError::WrongKind {
+            key: key.to_string(),
+            expected,
+            found: found.kind(),
+        }
+    }
+
+    /// Turns empty value into [FieldExportError::MissingKey].
+    fn required<T>(key: &str, value: Option<T>) -> Result<T, FieldExportError> {
+        value.ok_or_else(|| FieldExportError::MissingKey(key.to_string()))
+    }
+
+    /// Turns result into option, recording the error if in strict mode.
+    fn lenient<T>(&self, result: Result<T, FieldExportError>) -> Option<T> {
+        result
+            .inspect_err(|err| {
+                if let FieldExportError::MissingKey(key) = err
+                    && self.is_excluded(key)
+                {
+                    return;
+                }
+                if let Some(errors) = &self.errors {
+                    errors
+                        .borrow_mut()
+                        .insert(format!("{}{}", self.prefix, err.key()), err.to_string());
+                }
+            })
+            .ok()
     }
 
     /// Get value from string input.
@@ -32,55 +266,166 @@ impl FormExport {
 
     /// Get value from string input or None if empty.
     pub fn get_string_opt(&self, key: &str) -> Option<String> {
-        self.get(key).and_then(|export| {
-            if let FieldExport::String(val) = export {
-                nonify(val.clone())
-            } else {
-                None
-            }
-        })
+        self.lenient(self.try_get_string_opt(key)).flatten()
+    }
+
+    /// Get value from string input or error.
+    pub fn try_get_string(&self, key: &str) -> Result<String, FieldExportError> {
+        self.try_get_string_opt(key).map(Option::unwrap_or_default)
+    }
+
+    /// Get value from string input (None if empty) or error.
+    pub fn try_get_string_opt(&self, key: &str) -> Result<Option<String>, FieldExportError> {
+        match self.try_get(key)? {
+            FieldExport::String(val) => Ok(nonify(val.clone())),
+            other => Err(Self::wrong_kind(key, "string", other)),
+        }
     }
 
     // Get optional value from list field (string-based).
     pub fn list<T: From<String>>(&self, key: &str) -> Option<T> {
-        self.get(key).and_then(|export| {
-            if let FieldExport::List(val) = export {
-                nonify(val.clone()).map(Into::into)
-            } else {
-                None
-            }
-        })
+        self.lenient(self.try_list(key)).flatten()
     }
+
     // Get value from list field (string-based) or default.
     pub fn list_or_default<T: Default + From<String>>(&self, key: &str) -> T {
         self.list(key).unwrap_or_default()
     }
 
+    // Get optional value from list field (string-based) or error.
+    pub fn try_list<T: From<String>>(&self, key: &str) -> Result<Option<T>, FieldExportError> {
+        match self.try_get(key)? {
+            FieldExport::List(val) => Ok(nonify(val.clone()).map(Into::into)),
+            other => Err(Self::wrong_kind(key, "list", other)),
+        }
+    }
+
     // Get value from dict field (i64-based).
     pub fn dict_or_default<T: From<i64>>(&self, key: &str) -> T {
-        self.get(key)
-            .map(|export| {
-                if let FieldExport::Dict(val) = export {
-                    *val
-                } else {
-                    Default::default()
-                }
-            })
+        self.lenient(self.try_dict::<i64>(key))
             .unwrap_or_default()
             .into()
     }
 
+    // Get value from dict field (i64-based) or error.
+    pub fn try_dict<T: From<i64>>(&self, key: &str) -> Result<T, FieldExportError> {
+        match self.try_get(key)? {
+            FieldExport::Dict(val) => Ok((*val).into()),
+            other => Err(Self::wrong_kind(key, "dict", other)),
+        }
+    }
+
     // Get values from multi field (i64-based).
     pub fn multi<T: From<i64>>(&self, key: &str) -> Vec<T> {
-        self.get(key)
-            .map(|export| {
-                if let FieldExport::Multi(val) = export {
-                    val.iter().cloned().map(Into::into).collect()
-                } else {
-                    Default::default()
-                }
-            })
-            .unwrap_or_default()
+        self.lenient(self.try_multi(key)).unwrap_or_default()
+    }
+
+    // Get values from multi field (i64-based) or error.
+    pub fn try_multi<T: From<i64>>(&self, key: &str) -> Result<Vec<T>, FieldExportError> {
+        match self.try_get(key)? {
+            FieldExport::Multi(val) => Ok(val.iter().cloned().map(Into::into).collect()),
+            other => Err(Self::wrong_kind(key, "multi", other)),
+        }
+    }
+
+    /// Get value from number field or default.
+    pub fn number_or_default<T: FromStr + Default>(&self, key: &str) -> T {
+        self.get_number(key).unwrap_or_default()
+    }
+
+    /// Get value from number field or None if empty or not parsable as `T`.
+    ///
+    /// Parse failure is collected in [strict](FormExport::strict) mode like any other error.
+    pub fn get_number<T: FromStr>(&self, key: &str) -> Option<T> {
+        self.lenient(self.try_get_number(key)).flatten()
+    }
+
+    /// Get value from number field (None if empty) or error.
+    pub fn try_get_number<T: FromStr>(&self, key: &str) -> Result<Option<T>, FieldExportError> {
+        match self.try_get(key)? {
+            FieldExport::Number(val) if val.is_empty() => Ok(None),
+            FieldExport::Number(val) => {
+                val.parse()
+                    .map(Some)
+                    .map_err(|_| FieldExportError::InvalidValue {
+                        key: key.to_string(),
+                        message: format!("\"{val}\" is not a valid {}", type_name::<T>()),
+                    })
+            }
+            other => Err(Self::wrong_kind(key, "number", other)),
+        }
+    }
+
+    /// Get value from date field.
+    pub fn get_date(&self, key: &str) -> Option<Date> {
+        self.lenient(self.try_get_date(key)).flatten()
+    }
+
+    /// Get value from date field or default.
+    ///
+    /// Empty value is collected in [strict](FormExport::strict) mode as missing.
+    pub fn date_or_default(&self, key: &str) -> Date {
+        self.lenient(
+            self.try_get_date(key)
+                .and_then(|val| Self::required(key, val)),
+        )
+        .unwrap_or_default()
+    }
+
+    /// Get value from date field or error.
+    pub fn try_get_date(&self, key: &str) -> Result<Option<Date>, FieldExportError> {
+        match self.try_get(key)? {
+            FieldExport::Date(val) => Ok(*val),
+            other => Err(Self::wrong_kind(key, "date", other)),
+        }
+    }
+
+    /// Get value from time field.
+    pub fn get_time(&self, key: &str) -> Option<Time> {
+        self.lenient(self.try_get_time(key)).flatten()
+    }
+
+    /// Get value from time field or default.
+    ///
+    /// Empty value is collected in [strict](FormExport::strict) mode as missing.
+    pub fn time_or_default(&self, key: &str) -> Time {
+        self.lenient(
+            self.try_get_time(key)
+                .and_then(|val| Self::required(key, val)),
+        )
+        .unwrap_or_default()
+    }
+
+    /// Get value from time field or error.
+    pub fn try_get_time(&self, key: &str) -> Result<Option<Time>, FieldExportError> {
+        match self.try_get(key)? {
+            FieldExport::Time(val) => Ok(*val),
+            other => Err(Self::wrong_kind(key, "time", other)),
+        }
+    }
+
+    /// Get value from date and time field.
+    pub fn get_date_time(&self, key: &str) -> Option<DateTime> {
+        self.lenient(self.try_get_date_time(key)).flatten()
+    }
+
+    /// Get value from date and time field or default.
+    ///
+    /// Empty value is collected in [strict](FormExport::strict) mode as missing.
+    pub fn date_time_or_default(&self, key: &str) -> DateTime {
+        self.lenient(
+            self.try_get_date_time(key)
+                .and_then(|val| Self::required(key, val)),
+        )
+        .unwrap_or_default()
+    }
+
+    /// Get value from date and time field or error.
+    pub fn try_get_date_time(&self, key: &str) -> Result<Option<DateTime>, FieldExportError> {
+        match self.try_get(key)? {
+            FieldExport::DateTime(val) => Ok(*val),
+            other => Err(Self::wrong_kind(key, "datetime", other)),
+        }
     }
 
     /// Get value from bool input (i. e. checkbox) or false.
@@ -90,13 +435,15 @@ impl FormExport {
 
     /// Get value from bool input (i. e. checkbox) or None.
     pub fn get_bool_opt(&self, key: &str) -> Option<bool> {
-        self.get(key).and_then(|export| {
-            if let FieldExport::Bool(val) = export {
-                Some(*val)
-            } else {
-                None
-            }
-        })
+        self.lenient(self.try_get_bool(key))
+    }
+
+    /// Get value from bool input (i. e. checkbox) or error.
+    pub fn try_get_bool(&self, key: &str) -> Result<bool, FieldExportError> {
+        match self.try_get(key)? {
+            FieldExport::Bool(val) => Ok(*val),
+            other => Err(Self::wrong_kind(key, "bool", other)),
+        }
     }
 
     // Get new image (base64) or original value from image field.
@@ -106,40 +453,118 @@ impl FormExport {
 
     // Get new image (base64) or original value from image field or none.
     pub fn image_url_opt(&self, key: &str) -> Option<String> {
-        if let Some(export) = self.get(key) {
-            match export {
-                FieldExport::Image((orig_link, dfi)) => dfi
-                    .as_ref()
-                    .map(image_as_uri)
-                    .or_else(|| orig_link.as_ref().map(|ol| ol.to_string())),
-                _ => None,
-            }
-        } else {
-            None
-        }
+        self.lenient(self.try_image_url_opt(key)).flatten()
+    }
+
+    // Get new image (base64) or original value (or none) from image field or error.
+    pub fn try_image_url_opt(&self, key: &str) -> Result<Option<String>, FieldExportError> {
+        self.try_image(key).map(|(orig_link, dfi)| {
+            dfi.as_ref()
+                .map(image_as_uri)
+                .or_else(|| orig_link.as_ref().map(|ol| ol.to_string()))
+        })
     }
 
     // Get new image (base64)
     pub fn image_item_opt(&self, key: &str) -> Option<DropFileItem> {
-        if let Some(export) = self.get(key) {
-            match export {
-                FieldExport::Image((_orig_link, dfi)) => dfi.clone(),
-                _ => None,
-            }
-        } else {
-            None
-        }
+        self.lenient(self.try_image_item_opt(key)).flatten()
+    }
+
+    // Get new image (base64) or error.
+    pub fn try_image_item_opt(&self, key: &str) -> Result<Option<DropFileItem>, FieldExportError> {
+        self.try_image(key).map(|(_orig_link, dfi)| dfi.clone())
     }
 
     // Get original image url
     pub fn image_orig_url_opt(&self, key: &str) -> Option<Rc<String>> {
-        if let Some(export) = self.get(key) {
-            match export {
-                FieldExport::Image((orig_link, _dfi)) => orig_link.clone(),
-                _ => None,
-            }
-        } else {
-            None
+        self.lenient(self.try_image_orig_url_opt(key)).flatten()
+    }
+
+    // Get original image url or error.
+    pub fn try_image_orig_url_opt(
+        &self,
+        key: &str,
+    ) -> Result<Option<Rc<String>>, FieldExportError> {
+        self.try_image(key)
+            .map(|(orig_link, _dfi)| orig_link.clone())
+    }
+
+    /// Get fields of nested form (empty export if missing).
+    ///
+    /// In [strict](FormExport::strict) mode the sub-export shares errors with this export,
+    /// keyed by path (i.e. `address.city`).
+    pub fn nested(&self, key: &str) -> FormExport {
+        match self.lenient(self.try_nested(key)) {
+            Some(nested) => nested,
+            // Fields of excluded form are not expected either
+            None if self.is_excluded(key) => FormExport::new(Default::default()),
+            None => self.sub_export(&FormExport::new(Default::default()), key),
+        }
+    }
+
+    /// Get fields of nested form or error.
+    pub fn try_nested(&self, key: &str) -> Result<FormExport, FieldExportError> {
+        match self.try_get(key)? {
+            FieldExport::Nested(nested) => Ok(self.sub_export(nested, key)),
+            other => Err(Self::wrong_kind(key, "nested", other)),
+        }
+    }
+
+    /// Export placed under `path` of this one, sharing collected errors.
+    fn sub_export(&self, source: &FormExport, path: &str) -> Self {
+        Self {
+            map: source.map.clone(),
+            excluded: source.excluded.clone(),
+            errors: self.errors.clone(),
+            prefix: Rc::new(format!("{}{path}.", self.prefix)),
+        }
+    }
+
+    /// Get entries of group field.
+    pub fn group(&self, key: &str) -> Vec<FormExport> {
+        self.lenient(self.try_group(key)).unwrap_or_default()
+    }
+
+    /// Get entries of group field converted to models.
+    pub fn group_into<T: From<FormExport>>(&self, key: &str) -> Vec<T> {
+        self.group(key).into_iter().map(Into::into).collect()
+    }
+
+    /// Get entries of group field or error.
+    ///
+    /// In [strict](FormExport::strict) mode the entries share errors with this export,
+    /// keyed by path (i.e. `lines.0.price`).
+    pub fn try_group(&self, key: &str) -> Result<Vec<FormExport>, FieldExportError> {
+        match self.try_get(key)? {
+            FieldExport::Group(entries) => Ok(entries
+                .iter()
+                .enumerate()
+                .map(|(idx, entry)| self.sub_export(entry, &format!("{key}.{idx}")))
+                .collect()),
+            other => Err(Self::wrong_kind(key, "group", other)),
         }
     }
+
+    fn try_image<'a>(
+        &'a self,
+        key: &str,
+    ) -> Result<&'a (Option<Rc<String>>, Option<DropFileItem>), FieldExportError> {
+        match self.try_get(key)? {
+            FieldExport::Image(image) => Ok(image),
+            other => Err(Self::wrong_kind(key, "image", other)),
+        }
+    }
+}
+
+impl PartialEq for FormExport {
+    /// Compares values only.
+    fn eq(&self, other: &Self) -> bool {
+        self.map == other.map
+    }
+}
+
+impl std::fmt::Debug for FormExport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_map().entries(self.map.iter()).finish()
+    }
 }
@@ -1,19 +1,36 @@
-use std::rc::Rc;
+use std::{pin::Pin, rc::Rc};
 use vertigo::Value;
 
 use crate::ValidationErrors;
 
 mod data_field;
-pub use data_field::{CustomValue, DataFieldValue, ImageValue, TextAreaValue};
+pub use data_field::{
+    ComputedValue, CustomValue, DataFieldValue, DateFieldKind, DateParams, DateValue, GroupEntry,
+    GroupParams, GroupValue, ImageValue, NestedValue, NumberMode, NumberParams, NumberValue,
+    RadioValue, TextAreaValue,
+};
 
 mod form_export;
-pub use form_export::{FieldExport, FormExport};
+pub use form_export::{FieldExport, FieldExportError, FormExport};
+
+mod schema;
+pub use schema::{FormSchema, SchemaError};
+
+mod serialize;
+
+mod validator;
+pub use validator::{AsyncValidator, Validator};
 
 mod form_data;
-pub use form_data::{ControlsConfig, DataField, DataSection, FieldsetStyle, FormData};
+pub use form_data::{
+    ControlsConfig, DataField, DataSection, FieldsetStyle, FormData, SectionCondition,
+};
 
 pub type ValidateFunc<T> = Rc<dyn Fn(&T, Value<ValidationErrors>) -> bool>;
 
+/// Future returned by asynchronous callbacks of the form (async validators and submit).
+pub type FormFuture<R> = Pin<Box<dyn Future<Output = R>>>;
+
 #[derive(Default, Clone, PartialEq)]
 pub enum Operation {
     #[default]
@@ -0,0 +1,489 @@
+//! Building [FormData] at runtime from a JSON schema.
+
+use regex_lite::Regex;
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt::Display,
+    rc::Rc,
+};
+use vertigo::{Computed, JsJson, JsJsonNumber};
+
+use crate::{RadioGroupParams, locale};
+
+use super::{
+    DataSection, DateParams, FieldExport, FormData, FormExport, GroupParams, NumberParams,
+};
+
+/// Form definition loaded from a JSON schema, for forms configured by the backend.
+///
+/// Supported subset of JSON Schema:
+///
+/// * root is an `object` with `properties` and optional `required` list of keys,
+/// * `string` - text input, `enum` turns it into a dropdown, `format` can be `date`, `time`,
+///   `date-time` (date fields) or `data-url` (image field), validated by `minLength`, `maxLength`
+///   and `pattern`,
+/// * `integer`, `number` - number input validated by `minimum` and `maximum`,
+///   `oneOf` with integer `const` and `title` entries turns it into a dropdown of labelled integers,
+/// * `boolean` - checkbox,
+/// * `object` - nested form (see [DataSection::add_nested_field]),
+/// * `array` of `object` items - repeatable group (see [DataSection::add_group_field]),
+///   `array` of `integer` items with `oneOf` - multiselect,
+/// * `default` - value used when initial values don't provide one,
+/// * `title` - label of the section (defaults to capitalized key),
+/// * `description` - help text of the field (see [DataSection::help]).
+///
+/// UI hints (prefixed with `ui:`):
+///
+/// * `ui:widget` - `textarea`, `radio` or `segmented` (for `enum`/`oneOf`),
+/// * `ui:tab` - put the section into a tab with this label,
+/// * `ui:options` - object with integer keys and labels, alternative for `oneOf`,
+/// * `ui:order` - list of keys (in an `object`), as JSON objects are not ordered,
+///   remaining properties are rendered alphabetically,
+/// * `ui:placeholder` - placeholder of text-like controls,
+/// * `ui:controls` - list of `"top"`/`"bottom"` at the root, defaults to `["bottom"]`.
+///
+/// ```rust
+/// use std::collections::BTreeMap;
+/// use vertigo::{JsJson, JsJsonNumber};
+/// use vertigo_forms::form::FormSchema;
+///
+/// fn object(items: Vec<(&str, JsJson)>) -> JsJson {
+///     JsJson::Object(items.into_iter().map(|(k, v)| (k.to_string(), v)).collect::<BTreeMap<_, _>>())
+/// }
+/// let s = |s: &str| JsJson::String(s.to_string());
+///
+/// let schema = FormSchema::new(object(vec![
+///     ("type", s("object")),
+///     ("required", JsJson::List(vec![s("name")])),
+///     ("ui:order", JsJson::List(vec![s("name"), s("age"), s("bio")])),
+///     ("properties", object(vec![
+///         ("name", object(vec![("type", s("string")), ("title", s("Full name"))])),
+///         ("age", object(vec![("type", s("integer")), ("minimum", JsJson::Number(JsJsonNumber(0.)))])),
+///         ("bio", object(vec![("type", s("string")), ("ui:widget", s("textarea")), ("ui:tab", s("More"))])),
+///     ])),
+/// ]))
+/// .unwrap();
+///
+/// let form_data = schema.form_data(&object(vec![
+///     ("name", s("Ann")),
+///     ("age", JsJson::Number(JsJsonNumber(42.))),
+/// ]));
+/// assert_eq!(form_data.sections[0].label, "Full name");
+/// assert_eq!(form_data.tabs[0].0, "More");
+///
+/// let json = schema.to_json(&form_data.export());
+/// assert_eq!(json, object(vec![
+///     ("name", s("Ann")),
+///     ("age", JsJson::Number(JsJsonNumber(42.))),
+///     ("bio", s("")),
+/// ]));
+///
+/// // Options are keyed by integers
+/// let error = FormSchema::new(object(vec![
+///     ("type", s("object")),
+///     ("properties", object(vec![
+///         ("ratio", object(vec![
+///             ("type", s("number")),
+///             ("oneOf", JsJson::List(vec![object(vec![("const", JsJson::Number(JsJsonNumber(0.5)))])])),
+///         ])),
+///     ])),
+/// ]));
+/// assert_eq!(error.err().unwrap().path, "ratio");
+/// ```
+#[derive(Clone)]
+pub struct FormSchema {
+    schema: Rc<JsJson>,
+}
+
+/// Unsupported or malformed part of a [FormSchema].
+#[derive(Clone, Debug, PartialEq)]
+pub struct SchemaError {
+    /// Dotted path of the property (empty for root)
+    pub path: String,
+    pub message: String,
+}
+
+impl Display for SchemaError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.path.is_empty() {
+            write!(f, "{}", self.message)
+        } else {
+            write!(f, "Property \"{}\": {}", self.path, self.message)
+        }
+    }
+}
+
+impl std::error::Error for SchemaError {}
+
+impl FormSchema {
+    /// Check the schema and create a loader from it.
+    pub fn new(schema: JsJson) -> Result<Self, SchemaError> {
+        build_form(&schema, &JsJson::Null, "")?;
+        Ok(Self {
+            schema: Rc::new(schema),
+        })
+    }
+
+    /// Create [FormData] filled with initial values (JSON object, or `JsJson::Null` for a new entity).
+    ///
+    /// Values of unexpected type are ignored.
+    pub fn form_data(&self, values: &JsJson) -> FormData {
+        let form_data = build_form(&self.schema, values, "").unwrap_or_default();
+        match get(&self.schema, "ui:controls") {
+            Some(JsJson::List(controls)) => {
+                controls
+                    .iter()
+                    .fold(form_data, |form_data, item| match as_str(item) {
+                        Some("top") => form_data.add_top_controls(),
+                        Some("bottom") => form_data.add_bottom_controls(),
+                        _ => form_data,
+                    })
+            }
+            _ => form_data.add_bottom_controls(),
+        }
+    }
+
+    /// Convert export of the form to JSON object shaped by the schema.
+    ///
+    /// Works like [FormExport::to_json], but only properties present in the schema are emitted.
+    pub fn to_json(&self, export: &FormExport) -> JsJson {
+        export_json(&self.schema, export)
+    }
+}
+
+fn build_form(schema: &JsJson, values: &JsJson, path: &str) -> Result<FormData, SchemaError> {
+    let Some(JsJson::Object(properties)) = get(schema, "properties") else {
+        return Err(error(path, "expected object with \"properties\""));
+    };
+    let required = match get(schema, "required") {
+        Some(JsJson::List(list)) => list.iter().filter_map(as_str).collect(),
+        _ => Vec::new(),
+    };
+
+    let mut form_data = FormData::default();
+    let mut tabs = Vec::<(String, Vec<DataSection>)>::new();
+    for key in ordered_keys(schema, properties) {
+        let property = &properties[key];
+        let section = build_section(
+            key,
+            property,
+            get(values, key),
+            required.contains(&key),
+            &format!("{path}{key}"),
+        )?;
+        match get(property, "ui:tab").and_then(as_str) {
+            Some(tab) => match tabs.iter_mut().find(|(label, _)| label == tab) {
+                Some((_, sections)) => sections.push(section),
+                None => tabs.push((tab.to_string(), vec![section])),
+            },
+            None => form_data = form_data.with(section),
+        }
+    }
+    for (label, sections) in tabs {
+        form_data = form_data.add_tab(label, sections);
+    }
+    Ok(form_data)
+}
+
+fn ordered_keys<'a>(schema: &'a JsJson, properties: &'a BTreeMap<String, JsJson>) -> Vec<&'a str> {
+    let mut keys = match get(schema, "ui:order") {
+        Some(JsJson::List(order)) => order
+            .iter()
+            .filter_map(as_str)
+            .filter(|key| properties.contains_key(*key))
+            .collect(),
+        _ => Vec::new(),
+    };
+    for key in properties.keys() {
+        if !keys.contains(&key.as_str()) {
+            keys.push(key);
+        }
+    }
+    keys
+}
+
+fn build_section(
+    key: &str,
+    property: &JsJson,
+    value: Option<&JsJson>,
+    required: bool,
+    path: &str,
+) -> Result<DataSection, SchemaError> {
+    let label = match get(property, "title").and_then(as_str) {
+        Some(title) => title.to_string(),
+        None => capitalize(key),
+    };
+    let value = value
+        .filter(|value| !matches!(value, JsJson::Null))
+        .or_else(|| get(property, "default"));
+    let widget = get(property, "ui:widget").and_then(as_str);
+    let section = DataSection::new(label);
+
+    let section = match get(property, "type").and_then(as_str) {
+        Some("string") => string_field(section, key, property, value, widget, path)?,
+        Some(kind @ ("integer" | "number")) => match options(property, path)? {
+            Some(options) => {
+                let value = value.and_then(as_i64);
+                match widget {
+                    Some("radio") => {
+                        section.add_dict_radio_field(key, value, options, Default::default())
+                    }
+                    Some("segmented") => section.add_dict_radio_field(
+                        key,
+                        value,
+                        options,
+                        RadioGroupParams::segmented(),
+                    ),
+                    _ => section.add_static_dict_field(key, value, options),
+                }
+            }
+            None => {
+                let mut params = if kind == "integer" {
+                    NumberParams::integer()
+                } else {
+                    NumberParams::decimal()
+                };
+                if let Some(min) = get(property, "minimum").and_then(as_f64) {
+                    params = params.min(min);
+                }
+                if let Some(max) = get(property, "maximum").and_then(as_f64) {
+                    params = params.max(max);
+                }
+                section.add_number_field(key, value.and_then(as_f64), params)
+            }
+        },
+        Some("boolean") => {
+            return Ok(section.add_bool_field(key, value.and_then(as_bool)));
+        }
+        Some("object") => {
+            let nested = build_form(
+                property,
+                value.unwrap_or(&JsJson::Null),
+                &format!("{path}."),
+            )?;
+            return Ok(section.add_nested_field(key, nested));
+        }
+        Some("array") => {
+            let items = get(property, "items").cloned().unwrap_or(JsJson::Null);
+            let list = match value {
+                Some(JsJson::List(list)) => list.clone(),
+                _ => Vec::new(),
+            };
+            match get(&items, "type").and_then(as_str) {
+                Some("object") => {
+                    build_form(&items, &JsJson::Null, &format!("{path}.0."))?;
+                    return Ok(section.add_group_field(
+                        key,
+                        &list,
+                        move |item: Option<&JsJson>| {
+                            build_form(&items, item.unwrap_or(&JsJson::Null), "")
+                                .unwrap_or_default()
+                        },
+                        GroupParams::default(),
+                    ));
+                }
+                Some("integer") => {
+                    let Some(options) = options(&items, path)? else {
+                        return Err(error(
+                            path,
+                            "integer items require \"oneOf\" or \"ui:options\"",
+                        ));
+                    };
+                    let values = list.iter().filter_map(as_i64).collect();
+                    let options: HashMap<_, _> = options.into_iter().collect();
+                    section.add_multiselect_field(
+                        key,
+                        values,
+                        Computed::from(move |_ctx| options.clone()),
+                        &locale().add,
+                    )
+                }
+                _ => return Err(error(path, "unsupported type of array items")),
+            }
+        }
+        Some(other) => return Err(error(path, format!("unsupported type \"{other}\""))),
+        None => return Err(error(path, "missing \"type\"")),
+    };
+
+    let section = match get(property, "description").and_then(as_str) {
+        Some(description) => section.help(description),
+        None => section,
+    };
+    let section = match get(property, "ui:placeholder").and_then(as_str) {
+        Some(placeholder) => section.placeholder(placeholder),
+        None => section,
+    };
+
+    Ok(if required {
+        section.required()
+    } else {
+        section
+    })
+}
+
+fn string_field(
+    section: DataSection,
+    key: &str,
+    property: &JsJson,
+    value: Option<&JsJson>,
+    widget: Option<&str>,
+    path: &str,
+) -> Result<DataSection, SchemaError> {
+    let value = value.and_then(as_str);
+    let section = if let Some(JsJson::List(options)) = get(property, "enum") {
+        let options = options
+            .iter()
+            .filter_map(as_str)
+            .map(String::from)
+            .collect();
+        match widget {
+            Some("radio") => section.add_radio_field(key, value, options, Default::default()),
+            Some("segmented") => {
+                section.add_radio_field(key, value, options, RadioGroupParams::segmented())
+            }
+            _ => section.add_list_field(key, value, options),
+        }
+    } else {
+        // Time zones are not supported by date fields
+        let date_value = value.map(|value| value.trim_end_matches('Z'));
+        match get(property, "format").and_then(as_str) {
+            Some("date") => section.add_date_field(
+                key,
+                date_value.and_then(|value| value.parse().ok()),
+                DateParams::default(),
+            ),
+            Some("time") => section.add_time_field(
+                key,
+                date_value.and_then(|value| value.parse().ok()),
+                DateParams::default(),
+            ),
+            Some("date-time") => section.add_date_time_field(
+                key,
+                date_value.and_then(|value| value.parse().ok()),
+                DateParams::default(),
+            ),
+            Some("data-url") => section.add_image_field(key, value),
+            _ if widget == Some("textarea") => section.add_textarea_field(key, value, None, None),
+            _ => section.add_string_field(key, value.unwrap_or_default()),
+        }
+    };
+
+    let mut section = section;
+    if let Some(min) = get(property, "minLength").and_then(as_f64) {
+        section = section.min_len(min as usize);
+    }
+    if let Some(max) = get(property, "maxLength").and_then(as_f64) {
+        section = section.max_len(max as usize);
+    }
+    if let Some(pattern) = get(property, "pattern").and_then(as_str) {
+        let regex =
+            Regex::new(pattern).map_err(|err| error(path, format!("invalid pattern: {err}")))?;
+        section = section.pattern(regex);
+    }
+    Ok(section)
+}
+
+/// Labelled integer options from `oneOf` or `ui:options`.
+fn options(property: &JsJson, path: &str) -> Result<Option<Vec<(i64, String)>>, SchemaError> {
+    match (get(property, "oneOf"), get(property, "ui:options")) {
+        (Some(JsJson::List(list)), _) => list
+            .iter()
+            .map(|item| {
+                let value = get(item, "const")
+                    .and_then(as_i64)
+                    .ok_or_else(|| error(path, "\"const\" of \"oneOf\" must be an integer"))?;
+                let label = match get(item, "title").and_then(as_str) {
+                    Some(title) => title.to_string(),
+                    None => value.to_string(),
+                };
+                Ok((value, label))
+            })
+            .collect::<Result<_, _>>()
+            .map(Some),
+        (_, Some(JsJson::Object(map))) => Ok(Some(
+            map.iter()
+                .filter_map(|(key, label)| Some((key.parse().ok()?, as_str(label)?.to_string())))
+                .collect(),
+        )),
+        _ => Ok(None),
+    }
+}
+
+fn export_json(schema: &JsJson, export: &FormExport) -> JsJson {
+    let Some(JsJson::Object(properties)) = get(schema, "properties") else {
+        return export.to_json();
+    };
+    JsJson::Object(
+        properties
+            .iter()
+            .filter_map(|(key, property)| {
+                let value = match export.get(key)? {
+                    FieldExport::Nested(nested) => export_json(property, nested),
+                    FieldExport::Group(entries) => {
+                        let items = get(property, "items").unwrap_or(&JsJson::Null);
+                        JsJson::List(
+                            entries
+                                .iter()
+                                .map(|entry| export_json(items, entry))
+                                .collect(),
+                        )
+                    }
+                    value => value.to_json(),
+                };
+                Some((key.clone(), value))
+            })
+            .collect(),
+    )
+}
+
+fn error(path: &str, message: impl Into<String>) -> SchemaError {
+    SchemaError {
+        path: path.trim_end_matches('.').to_string(),
+        message: message.into(),
+    }
+}
+
+fn capitalize(key: &str) -> String {
+    let label = key.replace('_', " ");
+    let mut chars = label.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => label,
+    }
+}
+
+fn get<'a>(json: &'a JsJson, key: &str) -> Option<&'a JsJson> {
+    match json {
+        JsJson::Object(map) => map.get(key),
+        _ => None,
+    }
+}
+
+fn as_str(json: &JsJson) -> Option<&str> {
+    match json {
+        JsJson::String(value) => Some(value),
+        _ => None,
+    }
+}
+
+fn as_f64(json: &JsJson) -> Option<f64> {
+    match json {
+        JsJson::Number(JsJsonNumber(value)) => Some(*value),
+        _ => None,
+    }
+}
+
+/// Number without fractional part, as options are keyed by integers.
+pub(super) fn as_i64(json: &JsJson) -> Option<i64> {
+    as_f64(json)
+        .filter(|value| value.fract() == 0.0 && value.abs() < i64::MAX as f64)
+        .map(|value| value as i64)
+}
+
+fn as_bool(json: &JsJson) -> Option<bool> {
+    match json {
+        JsJson::True => Some(true),
+        JsJson::False => Some(false),
+        _ => None,
+    }
+}
@@ -0,0 +1,238 @@
+//! Conversions of [FormExport] into request bodies.
+
+use std::collections::BTreeMap;
+use vertigo::{DropFileItem, JsJson, JsJsonNumber};
+
+use crate::{image_as_uri, name_to_mime};
+
+use super::{FieldExport, FormExport};
+
+/// Flattened value of a field.
+enum Part {
+    Text(String),
+    File(DropFileItem),
+}
+
+impl FormExport {
+    /// Convert to JSON object, which can be sent using `RequestBuilder::body(RequestBody::Json(..))`.
+    ///
+    /// Numbers are converted to JSON numbers (if valid), empty numbers, dates and images to `null`.
+    /// New images are encoded as data URIs, otherwise the original link is used.
+    /// Groups become lists of objects and nested forms become objects.
+    ///
+    /// ```rust
+    /// use std::collections::HashMap;
+    /// use vertigo::{JsJson, JsJsonNumber};
+    /// use vertigo_forms::form::{FieldExport, FormExport};
+    ///
+    /// let export = FormExport::new(HashMap::from([
+    ///     ("name".to_string(), FieldExport::String("Ann".to_string())),
+    ///     ("age".to_string(), FieldExport::Number("42".to_string())),
+    ///     ("tags".to_string(), FieldExport::Multi(vec![1, 2])),
+    /// ]));
+    ///
+    /// assert_eq!(
+    ///     export.to_json(),
+    ///     JsJson::Object(
+    ///         [
+    ///             ("name".to_string(), JsJson::String("Ann".to_string())),
+    ///             ("age".to_string(), JsJson::Number(JsJsonNumber(42.0))),
+    ///             ("tags".to_string(), JsJson::List(vec![JsJson::Number(JsJsonNumber(1.0)), JsJson::Number(JsJsonNumber(2.0))])),
+    ///         ]
+    ///         .into()
+    ///     )
+    /// );
+    /// ```
+    pub fn to_json(&self) -> JsJson {
+        JsJson::Object(
+            self.iter()
+                .map(|(key, value)| (key.clone(), value.to_json()))
+                .collect(),
+        )
+    }
+
+    /// Convert to `application/x-www-form-urlencoded` body.
+    ///
+    /// Fields of nested forms and groups are keyed by path (i.e. `address.city`, `lines.0.price`),
+    /// multi fields repeat the key for every value. New images are encoded as data URIs.
+    ///
+    /// ```rust
+    /// use std::collections::HashMap;
+    /// use vertigo_forms::form::{FieldExport, FormExport};
+    ///
+    /// let address = FormExport::new(HashMap::from([
+    ///     ("city".to_string(), FieldExport::String("New York".to_string())),
+    /// ]));
+    /// let export = FormExport::new(HashMap::from([
+    ///     ("name".to_string(), FieldExport::String("Ann & Bob".to_string())),
+    ///     ("address".to_string(), FieldExport::Nested(address)),
+    ///     ("tags".to_string(), FieldExport::Multi(vec![1, 2])),
+    /// ]));
+    ///
+    /// assert_eq!(
+    ///     export.to_urlencoded(),
+    ///     "address.city=New+York&name=Ann+%26+Bob&tags=1&tags=2"
+    /// );
+    /// ```
+    pub fn to_urlencoded(&self) -> String {
+        self.parts()
+            .into_iter()
+            .map(|(key, part)| {
+                let value = match part {
+                    Part::Text(text) => text,
+                    Part::File(item) => image_as_uri(&item),
+                };
+                format!("{}={}", url_encode(&key), url_encode(&value))
+            })
+            .collect::<Vec<_>>()
+            .join("&")
+    }
+
+    /// Convert to `multipart/form-data` body, separated with provided boundary
+    /// (use `multipart/form-data; boundary=...` as content type).
+    ///
+    /// Keys are flattened the same way as in [to_urlencoded](FormExport::to_urlencoded),
+    /// but new images are sent as binary file parts.
+    ///
+    /// ```rust
+    /// use std::collections::HashMap;
+    /// use vertigo::DropFileItem;
+    /// use vertigo_forms::form::{FieldExport, FormExport};
+    ///
+    /// let export = FormExport::new(HashMap::from([
+    ///     ("name".to_string(), FieldExport::String("Ann".to_string())),
+    ///     ("photo".to_string(), FieldExport::Image((None, Some(DropFileItem::new("a.png".to_string(), vec![1, 2]))))),
+    /// ]));
+    ///
+    /// let body = export.to_multipart("XYZ");
+    /// let mut expected = b"--XYZ\r\nContent-Disposition: form-data; name=\"name\"\r\n\r\nAnn\r\n".to_vec();
+    /// expected.extend(b"--XYZ\r\nContent-Disposition: form-data; name=\"photo\"; filename=\"a.png\"\r\n");
+    /// expected.extend(b"Content-Type: image/png\r\n\r\n\x01\x02\r\n--XYZ--\r\n");
+    /// assert_eq!(body, expected);
+    /// ```
+    pub fn to_multipart(&self, boundary: &str) -> Vec<u8> {
+        let mut body = vec![];
+        for (key, part) in self.parts() {
+            body.extend(format!("--{boundary}\r\n").as_bytes());
+            let name = multipart_escape(&key);
+            match part {
+                Part::Text(text) => {
+                    body.extend(
+                        format!("Content-Disposition: form-data; name=\"{name}\"\r\n\r\n")
+                            .as_bytes(),
+                    );
+                    body.extend(text.as_bytes());
+                }
+                Part::File(item) => {
+                    body.extend(
+                        format!(
+                            "Content-Disposition: form-data; name=\"{name}\"; filename=\"{}\"\r\n",
+                            multipart_escape(&item.name)
+                        )
+                        .as_bytes(),
+                    );
+                    body.extend(
+                        format!("Content-Type: {}\r\n\r\n", name_to_mime(&item.name)).as_bytes(),
+                    );
+                    body.extend(item.data.iter());
+                }
+            }
+            body.extend(b"\r\n");
+        }
+        body.extend(format!("--{boundary}--\r\n").as_bytes());
+        body
+    }
+
+    /// Flattened fields, sorted by key path.
+    fn parts(&self) -> Vec<(String, Part)> {
+        let mut parts = vec![];
+        collect_parts(self, "", &mut parts);
+        parts
+    }
+}
+
+impl FieldExport {
+    /// Convert single field to JSON (see [FormExport::to_json]).
+    pub fn to_json(&self) -> JsJson {
+        let number = |val: i64| JsJson::Number(JsJsonNumber(val as f64));
+        let opt_string = |val: Option<String>| val.map(JsJson::String).unwrap_or(JsJson::Null);
+        match self {
+            FieldExport::Bool(val) => JsJson::bool(*val),
+            FieldExport::String(val) | FieldExport::List(val) => JsJson::String(val.clone()),
+            FieldExport::Dict(val) => number(*val),
+            FieldExport::Multi(val) => JsJson::List(val.iter().copied().map(number).collect()),
+            FieldExport::Number(val) if val.is_empty() => JsJson::Null,
+            FieldExport::Number(val) => match val.parse::<f64>() {
+                Ok(parsed) if parsed.is_finite() => JsJson::Number(JsJsonNumber(parsed)),
+                _ => JsJson::String(val.clone()),
+            },
+            FieldExport::Date(val) => opt_string(val.map(|val| val.to_string())),
+            FieldExport::Time(val) => opt_string(val.map(|val| val.to_string())),
+            FieldExport::DateTime(val) => opt_string(val.map(|val| val.to_string())),
+            FieldExport::Image((original_link, item)) => opt_string(
+                item.as_ref()
+                    .map(image_as_uri)
+                    .or_else(|| original_link.as_ref().map(|link| link.to_string())),
+            ),
+            FieldExport::Group(entries) => {
+                JsJson::List(entries.iter().map(FormExport::to_json).collect())
+            }
+            FieldExport::Nested(nested) => nested.to_json(),
+        }
+    }
+}
+
+fn collect_parts(export: &FormExport, prefix: &str, parts: &mut Vec<(String, Part)>) {
+    let sorted = export.iter().collect::<BTreeMap<_, _>>();
+    for (key, value) in sorted {
+        let path = format!("{prefix}{key}");
+        let text = |text: String| (path.clone(), Part::Text(text));
+        match value {
+            FieldExport::Bool(val) => parts.push(text(val.to_string())),
+            FieldExport::String(val) | FieldExport::List(val) | FieldExport::Number(val) => {
+                parts.push(text(val.clone()))
+            }
+            FieldExport::Dict(val) => parts.push(text(val.to_string())),
+            FieldExport::Multi(val) => parts.extend(val.iter().map(|val| text(val.to_string()))),
+            FieldExport::Date(val) => parts.push(text(opt_to_string(val))),
+            FieldExport::Time(val) => parts.push(text(opt_to_string(val))),
+            FieldExport::DateTime(val) => parts.push(text(opt_to_string(val))),
+            FieldExport::Image((_, Some(item))) => parts.push((path, Part::File(item.clone()))),
+            FieldExport::Image((original_link, None)) => {
+                parts.push(text(opt_to_string(original_link)))
+            }
+            FieldExport::Group(entries) => {
+                for (idx, entry) in entries.iter().enumerate() {
+                    collect_parts(entry, &format!("{path}.{idx}."), parts);
+                }
+            }
+            FieldExport::Nested(nested) => collect_parts(nested, &format!("{path}."), parts),
+        }
+    }
+}
+
+fn opt_to_string<T: ToString>(value: &Option<T>) -> String {
+    value.as_ref().map(ToString::to_string).unwrap_or_default()
+}
+
+/// Percent-encoding as in `application/x-www-form-urlencoded`.
+fn url_encode(text: &str) -> String {
+    let mut encoded = String::with_capacity(text.len());
+    for byte in text.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'*' | b'-' | b'.' | b'_' => {
+                encoded.push(byte as char)
+            }
+            b' ' => encoded.push('+'),
+            _ => encoded.push_str(&format!("%{byte:02X}")),
+        }
+    }
+    encoded
+}
+
+/// Escaping of field names and file names in multipart headers (as browsers do).
+fn multipart_escape(text: &str) -> String {
+    text.replace('"', "%22")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A")
+}
@@ -0,0 +1,328 @@
+use regex_lite::Regex;
+use std::{cell::Cell, fmt::Display, ops::RangeInclusive, rc::Rc, str::FromStr};
+use vertigo::{Computed, DropResource, Value, spawn, transaction};
+
+use crate::{defer, locale};
+
+use super::{
+    DataFieldValue, DateFieldKind, DateParams, FieldExport, FormFuture, NumberMode, NumberParams,
+};
+
+/// Validation rule attached to a [DataField](super::DataField).
+///
+/// Returns an error message if exported value of the field is not valid.
+/// Apart from [Validator::required], rules are not checked against empty values.
+///
+/// ```rust
+/// use vertigo_forms::form::{FieldExport, NumberParams, Validator};
+///
+/// let validator = Validator::min_len(3);
+/// assert_eq!(validator.validate(&FieldExport::String("ab".to_string())).unwrap(), "Must be at least 3 characters long");
+/// assert!(validator.validate(&FieldExport::String("".to_string())).is_none());
+///
+/// assert!(Validator::required().is_required());
+/// assert!(!validator.is_required());
+///
+/// let validator = Validator::range(1..=10);
+/// assert!(validator.validate(&FieldExport::String("5".to_string())).is_none());
+/// assert!(validator.validate(&FieldExport::String("11".to_string())).is_some());
+/// assert_eq!(validator.validate(&FieldExport::String("1.5".to_string())).unwrap(), "Must be an integer");
+/// assert_eq!(validator.validate(&FieldExport::String("abc".to_string())).unwrap(), "Must be a number");
+///
+/// let validator = Validator::number(&NumberParams::integer().min(0.0));
+/// assert_eq!(validator.validate(&FieldExport::Number("1.5".to_string())).unwrap(), "Must be an integer");
+/// assert_eq!(validator.validate(&FieldExport::Number("-1".to_string())).unwrap(), "Must be at least 0");
+/// ```
+#[derive(Clone)]
+pub struct Validator {
+    validate: ValidateFieldFunc,
+    required: bool,
+}
+
+type ValidateFieldFunc = Rc<dyn Fn(&FieldExport) -> Option<String>>;
+
+impl Validator {
+    /// Create validator from custom function.
+    pub fn new(validate: impl Fn(&FieldExport) -> Option<String> + 'static) -> Self {
+        Self {
+            validate: Rc::new(validate),
+            required: false,
+        }
+    }
+
+    /// Run validator against exported field value.
+    pub fn validate(&self, value: &FieldExport) -> Option<String> {
+        (self.validate)(value)
+    }
+
+    /// Field can not be empty (for dict it means value other than 0, for bool - checked,
+    /// for group - at least one entry).
+    pub fn required() -> Self {
+        let message = locale().required.clone();
+        Self {
+            required: true,
+            ..Self::new(move |value| is_empty(value).then(|| message.clone()))
+        }
+    }
+
+    /// Returns true for [Validator::required], used to mark required fields in rendered form.
+    pub fn is_required(&self) -> bool {
+        self.required
+    }
+
+    /// Minimal length of text, or minimal number of items in multi or group field.
+    pub fn min_len(min: usize) -> Self {
+        let locale = locale();
+        let message = locale
+            .min_len
+            .get(locale.plural_rule, min)
+            .replace("{min}", &min.to_string());
+        Self::new(move |value| {
+            length(value)
+                .filter(|len| *len > 0 && *len < min)
+                .map(|_| message.clone())
+        })
+    }
+
+    /// Maximal length of text, or maximal number of items in multi or group field.
+    pub fn max_len(max: usize) -> Self {
+        let locale = locale();
+        let message = locale
+            .max_len
+            .get(locale.plural_rule, max)
+            .replace("{max}", &max.to_string());
+        Self::new(move |value| {
+            length(value)
+                .filter(|len| *len > max)
+                .map(|_| message.clone())
+        })
+    }
+
+    /// Text must match provided regular expression.
+    pub fn pattern(regex: Regex) -> Self {
+        let message = locale().invalid_format.clone();
+        Self::new(move |value| {
+            text(value)
+                .filter(|text| !text.is_empty() && !regex.is_match(text))
+                .map(|_| message.clone())
+        })
+    }
+
+    /// Text must be a number satisfying [NumberParams] (mode and bounds, step is not checked).
+    pub fn number(params: &NumberParams) -> Self {
+        let NumberParams { min, max, mode, .. } = params.clone();
+        let locale = locale();
+        Self::new(move |value| {
+            let text = text(value).map(str::trim).filter(|text| !text.is_empty())?;
+            let number = match mode {
+                NumberMode::Integer => text.parse::<i64>().ok().map(|number| number as f64),
+                NumberMode::Decimal => text.parse::<f64>().ok().filter(|number| number.is_finite()),
+            };
+            let Some(number) = number else {
+                return Some(match mode {
+                    NumberMode::Integer => locale.not_integer.clone(),
+                    NumberMode::Decimal => locale.not_number.clone(),
+                });
+            };
+            if let Some(min) = min.filter(|min| number < *min) {
+                return Some(locale.min_value.replace("{min}", &min.to_string()));
+            }
+            if let Some(max) = max.filter(|max| number > *max) {
+                return Some(locale.max_value.replace("{max}", &max.to_string()));
+            }
+            None
+        })
+    }
+
+    /// Date, time or date with time must be within bounds from [DateParams].
+    pub fn date_bounds<T: DateFieldKind>(params: &DateParams<T>) -> Self {
+        let DateParams { min, max, format } = params.clone();
+        let locale = locale();
+        Self::new(move |value| {
+            let value = T::from_export(value)?;
+            if let Some(min) = min.filter(|min| value < *min) {
+                return Some(locale.min_date.replace("{min}", &min.format(&format)));
+            }
+            if let Some(max) = max.filter(|max| value > *max) {
+                return Some(locale.max_date.replace("{max}", &max.format(&format)));
+            }
+            None
+        })
+    }
+
+    /// Text (parsed as `T`) or dict value must be within provided range.
+    pub fn range<T>(range: RangeInclusive<T>) -> Self
+    where
+        T: PartialOrd + FromStr + Display + 'static,
+    {
+        let locale = locale();
+        let message = locale
+            .between
+            .replace("{min}", &range.start().to_string())
+            .replace("{max}", &range.end().to_string());
+        Self::new(move |value| {
+            let text = match value {
+                FieldExport::Dict(val) => val.to_string(),
+                other => text(other)?.trim().to_string(),
+            };
+            if text.is_empty() {
+                return None;
+            }
+            match text.parse::<T>() {
+                Ok(parsed) if range.contains(&parsed) => None,
+                Ok(_) => Some(message.clone()),
+                // I.e. decimal number for integer range
+                Err(_) if text.parse::<f64>().is_ok() => Some(locale.not_integer.clone()),
+                Err(_) => Some(locale.not_number.clone()),
+            }
+        })
+    }
+}
+
+fn text(value: &FieldExport) -> Option<&str> {
+    match value {
+        FieldExport::String(val) | FieldExport::List(val) | FieldExport::Number(val) => Some(val),
+        _ => None,
+    }
+}
+
+fn length(value: &FieldExport) -> Option<usize> {
+    match value {
+        FieldExport::Multi(val) => Some(val.len()),
+        FieldExport::Group(val) => Some(val.len()),
+        other => text(other).map(|text| text.chars().count()),
+    }
+}
+
+fn is_empty(value: &FieldExport) -> bool {
+    match value {
+        FieldExport::Bool(val) => !val,
+        FieldExport::String(val) | FieldExport::List(val) | FieldExport::Number(val) => {
+            val.trim().is_empty()
+        }
+        FieldExport::Dict(val) => *val == 0,
+        FieldExport::Multi(val) => val.is_empty(),
+        FieldExport::Group(val) => val.is_empty(),
+        FieldExport::Nested(_) => false,
+        FieldExport::Date(val) => val.is_none(),
+        FieldExport::Time(val) => val.is_none(),
+        FieldExport::DateTime(val) => val.is_none(),
+        FieldExport::Image((orig_link, dfi)) => orig_link.is_none() && dfi.is_none(),
+    }
+}
+
+type AsyncValidateFunc = Rc<dyn Fn(FieldExport) -> FormFuture<Option<String>>>;
+
+/// Asynchronous validation rule attached to a [DataField](super::DataField),
+/// i. e. for checks that need a server round-trip.
+///
+/// Validation is run every time the value of the field changes (but not for the original value),
+/// also when the field is not rendered (i. e. is on inactive tab).
+/// Responses of checks started for older values are discarded.
+#[derive(Clone)]
+pub struct AsyncValidator {
+    validate: AsyncValidateFunc,
+    run_id: Rc<Cell<u64>>,
+    subscription: Option<Rc<DropResource>>,
+    /// Check is in progress
+    pub pending: Value<bool>,
+    /// Result of the most recent check
+    pub error: Value<Option<String>>,
+}
+
+impl AsyncValidator {
+    /// Create validator from async function, which resolves to error message if invalid.
+    pub fn new<F, Fut>(validate: F) -> Self
+    where
+        F: Fn(FieldExport) -> Fut + 'static,
+        Fut: Future<Output = Option<String>> + 'static,
+    {
+        Self {
+            validate: Rc::new(move |value| Box::pin(validate(value))),
+            run_id: Default::default(),
+            subscription: None,
+            pending: Value::new(false),
+            error: Value::new(None),
+        }
+    }
+
+    /// Start validation of provided value.
+    pub fn run(&self, value: FieldExport) {
+        let run_id = self.next_run_id();
+        self.pending.set(true);
+        let future = (self.validate)(value);
+        let this = self.clone();
+        spawn(async move {
+            let error = future.await;
+            if this.run_id.get() == run_id {
+                transaction(|_| {
+                    this.error.set(error);
+                    this.pending.set(false);
+                });
+            }
+        });
+    }
+
+    /// Discard result of running validation and clear error.
+    pub fn cancel(&self) {
+        self.next_run_id();
+        transaction(|_| {
+            self.error.set(None);
+            self.pending.set(false);
+        });
+    }
+
+    /// Start validating changes of provided value, for as long as this validator is kept.
+    pub(crate) fn watch(mut self, value: &DataFieldValue) -> Self {
+        let runner = Self {
+            subscription: None,
+            ..self.clone()
+        };
+        let value = value.clone();
+        let subscription = Computed::from(move |ctx| (value.is_changed(ctx), value.export(ctx)))
+            .subscribe(move |(changed, export)| {
+                let runner = runner.clone();
+                defer(move || {
+                    if changed {
+                        runner.run(export);
+                    } else {
+                        runner.cancel();
+                    }
+                });
+            });
+        self.subscription = Some(Rc::new(subscription));
+        self
+    }
+
+    fn next_run_id(&self) -> u64 {
+        let run_id = self.run_id.get() + 1;
+        self.run_id.set(run_id);
+        run_id
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use vertigo::transaction;
+
+    use crate::{
+        deferred::run_deferred,
+        form::{DataFieldValue, DataSection},
+    };
+
+    #[test]
+    fn async_validator_runs_without_rendering() {
+        let section = DataSection::with_string_field("Login", "login", "ann")
+            .validate_async(|_login| async { Some("Login taken".to_string()) });
+        let field = &section.fields[0];
+        let async_validator = field.async_validator.as_ref().unwrap();
+        run_deferred();
+        assert!(!transaction(|ctx| async_validator.pending.get(ctx)));
+
+        if let DataFieldValue::String(login) = &field.value {
+            login.value.set("bob".to_string());
+        }
+        run_deferred();
+        assert!(transaction(|ctx| async_validator.pending.get(ctx)));
+    }
+}
@@ -0,0 +1,206 @@
+use std::{cell::Cell, collections::BTreeMap, rc::Rc};
+use vertigo::{
+    Computed, DomNode, DropResource, JsJson, JsJsonNumber, Value, bind, css, dom, get_driver, js,
+    transaction,
+};
+
+use crate::theme::var;
+
+use super::FormData;
+
+/// Labels of draft restore offer.
+pub(super) struct DraftLabels {
+    pub found: Rc<String>,
+    pub restore: Rc<String>,
+    pub discard: Rc<String>,
+}
+
+/// Unsaved values of a form kept in browser's local storage (see [FormParams::draft_key](super::FormParams::draft_key)).
+#[derive(Clone)]
+pub(super) struct Draft {
+    key: Rc<String>,
+    version: u32,
+    /// Delay (in milliseconds) after the last change before the draft is saved
+    debounce: u32,
+    /// Values found in storage on mount, waiting for the user to restore or discard them
+    offer: Value<Option<JsJson>>,
+    /// Bumped on every change, so only the last scheduled save is performed
+    generation: Rc<Cell<u32>>,
+}
+
+impl Draft {
+    pub fn new(key: Rc<String>, version: u32, debounce: u32) -> Self {
+        let offer = Value::new(load(&key, version));
+        Self {
+            key,
+            version,
+            debounce,
+            offer,
+            generation: Default::default(),
+        }
+    }
+
+    /// Save values of the form (debounced) as long as returned resource is kept.
+    ///
+    /// Nothing is saved while restore offer is pending, and the draft is removed if form gets back to original values.
+    pub fn subscribe(&self, form_data: &Rc<FormData>) -> DropResource {
+        let state = Computed::from(bind!(form_data, self.offer, |ctx| {
+            if offer.get(ctx).is_some() {
+                return None;
+            }
+            let dirty = form_data
+                .all_fields()
+                .any(|field| field.value.is_changed(ctx));
+            Some(dirty.then(|| form_data.export_in(ctx).to_json()))
+        }));
+
+        let this = self.clone();
+        state.subscribe(move |state| match state {
+            Some(Some(values)) => this.save(values),
+            Some(None) => this.clear(),
+            None => {}
+        })
+    }
+
+    /// Remove the draft, i.e. after the form is submitted.
+    pub fn clear(&self) {
+        self.generation.set(self.generation.get() + 1);
+        if get_driver().is_browser() {
+            let key = self.key.to_string();
+            js! { window.localStorage.removeItem(key) };
+        }
+    }
+
+    fn save(&self, values: JsJson) {
+        let generation = self.generation.get() + 1;
+        self.generation.set(generation);
+        if !get_driver().is_browser() {
+            return;
+        }
+        let this = self.clone();
+        get_driver().spawn(async move {
+            get_driver().sleep(this.debounce).await;
+            if this.generation.get() != generation {
+                return;
+            }
+            let key = this.key.to_string();
+            let draft = encode(this.version, values);
+            js! { window.localStorage.setItem(key, draft) };
+        });
+    }
+
+    /// Renders offer to restore values found in storage.
+    pub fn render(&self, form_data: &Rc<FormData>, labels: DraftLabels) -> DomNode {
+        let this = self.clone();
+        let form_data = form_data.clone();
+        self.offer.render_value_option(move |values| {
+            let values = values?;
+            let on_restore = bind!(this, form_data, |_| {
+                transaction(|_| {
+                    form_data.load_json(&values);
+                    this.offer.set(None);
+                })
+            });
+            let on_discard = bind!(this, |_| {
+                this.clear();
+                this.offer.set(None);
+            });
+            let css = css! {"
+                grid-column: span 2;
+                display: flex;
+                align-items: center;
+                gap: 5px;
+                padding: 5px;
+                background: {var::WARNING_BACKGROUND};
+                border: 1px solid;
+                border-color: {var::WARNING_BORDER};
+            "};
+            Some(dom! {
+                <div {css}>
+                    <span>{&labels.found}</span>
+                    <input type="button" value={&labels.restore} on_click={on_restore} />
+                    <input type="button" value={&labels.discard} on_click={on_discard} />
+                </div>
+            })
+        })
+    }
+}
+
+/// Read draft from storage, drafts of other version (or malformed) are removed.
+fn load(key: &str, version: u32) -> Option<JsJson> {
+    if !get_driver().is_browser() {
+        return None;
+    }
+    let key = key.to_string();
+    let stored = js! { window.localStorage.getItem(key.clone()) };
+    let JsJson::String(stored) = stored else {
+        return None;
+    };
+    let values = decode(&stored, version);
+    if values.is_none() {
+        js! { window.localStorage.removeItem(key) };
+    }
+    values
+}
+
+/// Serialize values of the form along with version of the draft.
+fn encode(version: u32, values: JsJson) -> String {
+    JsJson::Object(BTreeMap::from([
+        (
+            "version".to_string(),
+            JsJson::Number(JsJsonNumber(version as f64)),
+        ),
+        ("values".to_string(), values),
+    ]))
+    .convert_to_string()
+}
+
+/// Values of stored draft, `None` if it's malformed or of other version.
+fn decode(stored: &str, version: u32) -> Option<JsJson> {
+    match JsJson::from_string(stored) {
+        Ok(JsJson::Object(mut draft))
+            if draft.get("version") == Some(&JsJson::Number(JsJsonNumber(version as f64))) =>
+        {
+            draft.remove("values")
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use vertigo::{JsJson, transaction};
+
+    use super::{decode, encode};
+    use crate::form::{DataSection, FormData};
+
+    fn form_data(name: &str, amount: f64, active: bool) -> FormData {
+        FormData::default().with(
+            DataSection::with_string_field("Name", "name", name)
+                .add_number_field("amount", Some(amount), Default::default())
+                .add_bool_field("active", Some(active)),
+        )
+    }
+
+    #[test]
+    fn other_version_is_discarded() {
+        let stored = encode(1, JsJson::Null);
+        assert_eq!(decode(&stored, 1), Some(JsJson::Null));
+        assert_eq!(decode(&stored, 2), None);
+        assert_eq!(decode("{\"values\": null}", 1), None);
+        assert_eq!(decode("not a json", 1), None);
+    }
+
+    #[test]
+    fn restore_round_trip() {
+        let edited = form_data("Anna", 12.5, true);
+        let values = transaction(|ctx| edited.export_in(ctx).to_json());
+        let stored = encode(3, values.clone());
+
+        let restored = form_data("Ann", 10.0, false);
+        restored.load_json(&decode(&stored, 3).unwrap());
+
+        assert_eq!(restored.export().to_json(), values);
+        assert_eq!(restored.export().get_string("name"), "Anna");
+    }
+}
@@ -0,0 +1,410 @@
+use std::{cell::RefCell, rc::Rc};
+use vertigo::{
+    Computed, DropResource, InstantType, JsJson, KeyDownEvent, Value, bind, get_driver, transaction,
+};
+
+use crate::defer;
+
+use super::{FieldExport, FormData, FormExport};
+
+/// Changes of the same fields made within this time (in milliseconds) are merged into one step
+const COALESCE_TIME: InstantType = 1000;
+
+#[derive(Clone, Default, PartialEq)]
+struct HistoryState {
+    undo: Vec<JsJson>,
+    redo: Vec<JsJson>,
+}
+
+#[derive(Default)]
+struct Snapshots {
+    form_data: Option<Rc<FormData>>,
+    /// Values of the form after the last recorded change
+    current: Option<JsJson>,
+    /// Keys modified by the last change and its time, used to merge consecutive changes
+    last_change: Option<(Vec<String>, Option<InstantType>)>,
+}
+
+/// Undo/redo history of values of a [FormData].
+///
+/// Pass it to [FormParams::history](super::FormParams::history) (or [attach](FormHistory::attach) it manually)
+/// and keep a clone to render undo/redo controls. Every change of values is recorded,
+/// changes of the same fields made in quick succession (i.e. typing) are merged into one step.
+///
+/// Image fields are not recorded, so changing an image adds no undo step and keeps redo history,
+/// and undo leaves images as they are.
+///
+/// Merging needs a clock, which is only available in the browser by default
+/// (see [FormHistory::with_clock]), elsewhere every change is a separate step.
+///
+/// ```rust,no_run
+/// use std::rc::Rc;
+/// use vertigo::transaction;
+/// use vertigo_forms::form::{DataFieldValue, DataSection, FormData, FormHistory};
+///
+/// let form_data = Rc::new(
+///     FormData::default()
+///         .with(DataSection::with_string_field("Name", "name", "Ann"))
+///         .with(DataSection::with_string_field("City", "city", "Oslo")),
+/// );
+/// let history = FormHistory::default();
+/// let _subscription = history.attach(&form_data);
+///
+/// let set = |key: &str, value: &str| {
+///     let field = form_data.all_fields().find(|field| field.key == key).unwrap();
+///     if let DataFieldValue::String(val) = &field.value {
+///         val.value.set(value.to_string());
+///     }
+/// };
+/// set("name", "Anna");
+/// set("city", "Bergen");
+///
+/// history.undo();
+/// assert_eq!(form_data.export().get_string("city"), "Oslo");
+/// history.undo();
+/// assert_eq!(form_data.export().get_string("name"), "Ann");
+/// assert!(!transaction(|ctx| history.can_undo().get(ctx)));
+///
+/// history.redo();
+/// assert_eq!(form_data.export().get_string("name"), "Anna");
+/// assert!(transaction(|ctx| history.can_redo().get(ctx)));
+/// ```
+#[derive(Clone, Default)]
+pub struct FormHistory {
+    state: Value<HistoryState>,
+    snapshots: Rc<RefCell<Snapshots>>,
+    clock: Option<Rc<dyn Fn() -> InstantType>>,
+    /// Bind Ctrl+Z (undo), Ctrl+Y and Ctrl+Shift+Z (redo) in the form, Cmd is accepted as well
+    pub shortcuts: bool,
+}
+
+impl FormHistory {
+    /// Bind keyboard shortcuts in the form (see [FormHistory::shortcuts]).
+    pub fn with_shortcuts(mut self) -> Self {
+        self.shortcuts = true;
+        self
+    }
+
+    /// Use custom source of current time (in milliseconds) for merging quick changes,
+    /// instead of the browser's clock.
+    pub fn with_clock(mut self, clock: impl Fn() -> InstantType + 'static) -> Self {
+        self.clock = Some(Rc::new(clock));
+        self
+    }
+
+    /// Start recording changes of the form as long as returned resource is kept.
+    ///
+    /// Previous history is cleared.
+    pub fn attach(&self, form_data: &Rc<FormData>) -> DropResource {
+        self.snapshots.replace(Snapshots {
+            form_data: Some(form_data.clone()),
+            ..Default::default()
+        });
+        self.state.set(Default::default());
+
+        let values = Computed::from(bind!(form_data, |ctx| snapshot(&form_data.export_in(ctx))));
+        let this = self.clone();
+        values.subscribe(move |values| this.record(values))
+    }
+
+    pub fn can_undo(&self) -> Computed<bool> {
+        self.state.to_computed().map(|state| !state.undo.is_empty())
+    }
+
+    pub fn can_redo(&self) -> Computed<bool> {
+        self.state.to_computed().map(|state| !state.redo.is_empty())
+    }
+
+    /// Revert the last change.
+    pub fn undo(&self) {
+        self.step(|state| &mut state.undo, |state| &mut state.redo);
+    }
+
+    /// Re-apply the last undone change.
+    pub fn redo(&self) {
+        self.step(|state| &mut state.redo, |state| &mut state.undo);
+    }
+
+    /// Handler for keydown event, returns true if the shortcut was used.
+    pub(super) fn on_key_down(&self, event: KeyDownEvent) -> bool {
+        if !event.ctrl_key && !event.meta_key {
+            return false;
+        }
+        match event.key.to_lowercase().as_str() {
+            "z" if event.shift_key => self.redo(),
+            "z" => self.undo(),
+            "y" => self.redo(),
+            _ => return false,
+        }
+        true
+    }
+
+    fn record(&self, values: JsJson) {
+        let mut snapshots = self.snapshots.borrow_mut();
+        let Some(previous) = snapshots.current.replace(values.clone()) else {
+            // Initial values
+            return;
+        };
+        if previous == values {
+            return;
+        }
+
+        let keys = changed_keys(&previous, &values);
+        let now = match &self.clock {
+            Some(clock) => Some(clock()),
+            None => {
+                let driver = get_driver();
+                driver.is_browser().then(|| driver.utc_now())
+            }
+        };
+        let merge = match &snapshots.last_change {
+            Some((last_keys, Some(last_time))) => {
+                *last_keys == keys && now.is_some_and(|now| now - last_time < COALESCE_TIME)
+            }
+            _ => false,
+        };
+        snapshots.last_change = Some((keys, now));
+        drop(snapshots);
+
+        if !merge {
+            let state = self.state.clone();
+            defer(move || {
+                state.change(|state| {
+                    state.undo.push(previous);
+                    state.redo.clear();
+                })
+            });
+        }
+    }
+
+    fn step(
+        &self,
+        from: impl FnOnce(&mut HistoryState) -> &mut Vec<JsJson>,
+        to: impl FnOnce(&mut HistoryState) -> &mut Vec<JsJson>,
+    ) {
+        let (form_data, current) = {
+            let snapshots = self.snapshots.borrow();
+            match (&snapshots.form_data, &snapshots.current) {
+                (Some(form_data), Some(current)) => (form_data.clone(), current.clone()),
+                _ => return,
+            }
+        };
+
+        let mut target = None;
+        self.state.change(|state| {
+            if let Some(values) = from(state).pop() {
+                to(state).push(current);
+                target = Some(values);
+            }
+        });
+        let Some(target) = target else {
+            return;
+        };
+
+        {
+            let mut snapshots = self.snapshots.borrow_mut();
+            snapshots.current = Some(target.clone());
+            snapshots.last_change = None;
+        }
+        transaction(|_| form_data.load_json(&target));
+    }
+}
+
+/// Values of the form restorable by [FormData::load_json], images are left out.
+fn snapshot(export: &FormExport) -> JsJson {
+    JsJson::Object(
+        export
+            .iter()
+            .filter_map(|(key, value)| {
+                let json = match value {
+                    FieldExport::Image(_) => return None,
+                    FieldExport::Group(entries) => {
+                        JsJson::List(entries.iter().map(snapshot).collect())
+                    }
+                    FieldExport::Nested(nested) => snapshot(nested),
+                    value => value.to_json(),
+                };
+                Some((key.clone(), json))
+            })
+            .collect(),
+    )
+}
+
+/// Keys of top-level values which differ between snapshots.
+fn changed_keys(previous: &JsJson, values: &JsJson) -> Vec<String> {
+    match (previous, values) {
+        (JsJson::Object(previous), JsJson::Object(values)) => values
+            .iter()
+            .filter(|(key, value)| previous.get(*key) != Some(value))
+            .map(|(key, _)| key.clone())
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::Cell, rc::Rc};
+    use vertigo::{DropFileItem, transaction};
+
+    use super::FormHistory;
+    use crate::{
+        deferred::run_deferred,
+        form::{DataFieldValue, DataSection, FormData, GroupParams},
+    };
+
+    fn set_string(form_data: &FormData, key: &str, value: &str) {
+        let field = form_data
+            .all_fields()
+            .find(|field| field.key == key)
+            .unwrap();
+        if let DataFieldValue::String(val) = &field.value {
+            val.value.set(value.to_string());
+        }
+        run_deferred();
+    }
+
+    #[test]
+    fn undo_redo() {
+        let form_data = Rc::new(
+            FormData::default()
+                .with(DataSection::with_string_field("Name", "name", "Ann"))
+                .with(DataSection::with_string_field("City", "city", "Oslo")),
+        );
+        let history = FormHistory::default();
+        let _subscription = history.attach(&form_data);
+
+        set_string(&form_data, "name", "Anna");
+        set_string(&form_data, "city", "Bergen");
+
+        history.undo();
+        assert_eq!(form_data.export().get_string("city"), "Oslo");
+        history.undo();
+        assert_eq!(form_data.export().get_string("name"), "Ann");
+        assert!(!transaction(|ctx| history.can_undo().get(ctx)));
+
+        history.redo();
+        assert_eq!(form_data.export().get_string("name"), "Anna");
+        assert!(transaction(|ctx| history.can_redo().get(ctx)));
+    }
+
+    fn can_undo_redo(history: &FormHistory) -> (bool, bool) {
+        transaction(|ctx| (history.can_undo().get(ctx), history.can_redo().get(ctx)))
+    }
+
+    #[test]
+    fn undo_redo_group_entries() {
+        let form_data = Rc::new(FormData::default().with(
+            DataSection::new("Lines").add_group_field(
+                "lines",
+                &["Nails"],
+                |name: Option<&&str>| {
+                    FormData::default().with(DataSection::with_string_field(
+                        "Name",
+                        "name",
+                        name.copied().unwrap_or_default(),
+                    ))
+                },
+                GroupParams::default(),
+            ),
+        ));
+        let history = FormHistory::default();
+        let _subscription = history.attach(&form_data);
+        let names = || {
+            form_data
+                .export()
+                .group("lines")
+                .iter()
+                .map(|line| line.get_string("name"))
+                .collect::<Vec<_>>()
+        };
+        let DataFieldValue::Group(group) = &form_data.sections[0].fields[0].value else {
+            unreachable!()
+        };
+
+        group.add();
+        run_deferred();
+        let added = &transaction(|ctx| group.value.get(ctx))[1];
+        set_string(added, "name", "Screws");
+        group.remove(0);
+        run_deferred();
+        assert_eq!(names(), ["Screws"]);
+
+        history.undo();
+        assert_eq!(names(), ["Nails", "Screws"]);
+        history.undo();
+        assert_eq!(names(), ["Nails", ""]);
+        history.undo();
+        assert_eq!(names(), ["Nails"]);
+        assert_eq!(can_undo_redo(&history), (false, true));
+
+        history.redo();
+        history.redo();
+        assert_eq!(names(), ["Nails", "Screws"]);
+        history.redo();
+        assert_eq!(names(), ["Screws"]);
+        assert_eq!(can_undo_redo(&history), (true, false));
+    }
+
+    #[test]
+    fn image_changes_are_not_recorded() {
+        let form_data = Rc::new(
+            FormData::default().with(
+                DataSection::with_string_field("Name", "name", "Ann")
+                    .add_image_field("photo", None::<String>),
+            ),
+        );
+        let history = FormHistory::default();
+        let _subscription = history.attach(&form_data);
+
+        set_string(&form_data, "name", "Anna");
+        history.undo();
+        assert_eq!(can_undo_redo(&history), (false, true));
+
+        let photo = form_data.all_fields().find(|field| field.key == "photo");
+        if let Some(DataFieldValue::Image(val)) = photo.map(|field| &field.value) {
+            val.value.set(Some(DropFileItem::new(
+                "photo.png".to_string(),
+                vec![1, 2, 3],
+            )));
+        }
+        run_deferred();
+        assert_eq!(can_undo_redo(&history), (false, true));
+
+        history.redo();
+        assert_eq!(form_data.export().get_string("name"), "Anna");
+        assert!(form_data.export().image_item_opt("photo").is_some());
+    }
+
+    #[test]
+    fn quick_changes_are_merged() {
+        let now = Rc::new(Cell::new(0));
+        let form_data = Rc::new(
+            FormData::default()
+                .with(DataSection::with_string_field("Name", "name", "Ann"))
+                .with(DataSection::with_string_field("City", "city", "Oslo")),
+        );
+        let history = FormHistory::default().with_clock({
+            let now = now.clone();
+            move || now.get()
+        });
+        let _subscription = history.attach(&form_data);
+
+        set_string(&form_data, "name", "Ann");
+        set_string(&form_data, "name", "Anna");
+        now.set(500);
+        set_string(&form_data, "name", "Annabel");
+        now.set(2000);
+        set_string(&form_data, "name", "Anne");
+        now.set(2100);
+        set_string(&form_data, "city", "Bergen");
+
+        history.undo();
+        assert_eq!(form_data.export().get_string("city"), "Oslo");
+        history.undo();
+        assert_eq!(form_data.export().get_string("name"), "Annabel");
+        history.undo();
+        assert_eq!(form_data.export().get_string("name"), "Ann");
+        assert_eq!(can_undo_redo(&history), (false, true));
+    }
+}
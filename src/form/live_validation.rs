@@ -0,0 +1,143 @@
+use std::{
+    cell::RefCell,
+    collections::{HashMap, HashSet},
+    rc::Rc,
+};
+use vertigo::{Computed, Context, DropResource, Value, bind, transaction};
+
+use crate::{ValidationErrors, defer};
+
+use super::{FieldExport, FormData, FormExport, ValidateFunc, ValidationMode};
+
+/// Tracks user interaction with the form, to show live validation errors only for fields
+/// that were already modified (or blurred) by the user.
+#[derive(Clone)]
+pub(super) struct LiveValidation {
+    mode: ValidationMode,
+    /// Snapshot of values from previous run
+    previous: Rc<RefCell<Option<HashMap<String, FieldExport>>>>,
+    /// Keys of fields modified since mount (or reset)
+    modified: Rc<RefCell<HashSet<String>>>,
+    /// Keys of errors set by previous run, replaced by the next one
+    owned: Rc<RefCell<HashSet<String>>>,
+    pub submitted: Value<bool>,
+    /// Keys of fields that lost focus, tracked only in [ValidationMode::OnBlur]
+    pub blurred: Option<Value<HashSet<String>>>,
+}
+
+impl LiveValidation {
+    pub fn new(mode: ValidationMode) -> Self {
+        Self {
+            mode,
+            previous: Default::default(),
+            modified: Default::default(),
+            owned: Default::default(),
+            submitted: Value::new(false),
+            blurred: (mode == ValidationMode::OnBlur).then(Default::default),
+        }
+    }
+
+    /// Re-run validation on every change of the form, as long as returned resource is kept.
+    ///
+    /// Returns `None` in [ValidationMode::OnSubmit].
+    pub fn subscribe<T>(
+        &self,
+        form_data: &Rc<FormData>,
+        validate: Option<ValidateFunc<T>>,
+        validation_errors: &Value<ValidationErrors>,
+    ) -> Option<DropResource>
+    where
+        T: From<FormExport> + 'static,
+    {
+        if self.mode == ValidationMode::OnSubmit {
+            return None;
+        }
+
+        let state = Computed::from(bind!(form_data, self.submitted, self.blurred, |ctx| {
+            let errors = form_data.validate_fields_in(ctx);
+            let blurred = blurred
+                .as_ref()
+                .map(|blurred| blurred.get(ctx))
+                .unwrap_or_default();
+            (
+                snapshot(&form_data, ctx),
+                errors,
+                blurred,
+                submitted.get(ctx),
+            )
+        }));
+
+        let this = self.clone();
+        Some(state.subscribe(bind!(form_data, validation_errors, |(
+            values,
+            mut errors,
+            blurred,
+            submitted,
+        )| {
+            let Some(previous) = this.previous.replace(Some(values.clone())) else {
+                // Don't touch errors on mount
+                return;
+            };
+            this.modified.borrow_mut().extend(
+                values
+                    .iter()
+                    .filter(|(key, value)| previous.get(*key) != Some(value))
+                    .map(|(key, _)| key.clone()),
+            );
+
+            if this.mode == ValidationMode::OnSubmitThenChange && !submitted {
+                return;
+            }
+
+            let modified = this.modified.borrow().clone();
+            let mode = this.mode;
+            let owned = this.owned.clone();
+            defer(bind!(form_data, validation_errors, validate, || {
+                if let Some(validate) = &validate {
+                    let model_errors = Value::new(ValidationErrors::new());
+                    validate(&form_data.export().into(), model_errors.clone());
+                    for (key, error) in transaction(|ctx| model_errors.get(ctx)) {
+                        errors.entry(key).or_insert(error);
+                    }
+                }
+
+                errors.retain(|key, _| {
+                    submitted
+                        || match mode {
+                            ValidationMode::OnBlur => blurred.contains(key),
+                            // Fields nested in groups are tracked by the group key
+                            _ => modified.contains(key.split('.').next().unwrap_or_default()),
+                        }
+                });
+                // Keep errors set on submit or by the application
+                let previous = owned.replace(errors.keys().cloned().collect());
+                validation_errors.change(|all_errors| {
+                    all_errors.retain(|key, _| {
+                        !previous.contains(key) && !form_data.is_field_error_key(key)
+                    });
+                    all_errors.extend(errors);
+                });
+            }));
+        })))
+    }
+
+    /// Forget all interactions, current values become the starting point.
+    pub fn restart(&self, form_data: &FormData) {
+        transaction(|ctx| {
+            self.previous.replace(Some(snapshot(form_data, ctx)));
+            self.modified.borrow_mut().clear();
+            self.owned.borrow_mut().clear();
+            self.submitted.set(false);
+            if let Some(blurred) = &self.blurred {
+                blurred.set(Default::default());
+            }
+        });
+    }
+}
+
+fn snapshot(form_data: &FormData, ctx: &Context) -> HashMap<String, FieldExport> {
+    form_data
+        .all_fields()
+        .map(|field| (field.key.clone(), field.value.export(ctx)))
+        .collect()
+}
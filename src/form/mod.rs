@@ -2,59 +2,312 @@
 //!
 //! The model needs to implement converting to [FormData] and from [FormExport],
 //! then it can be passed directly to [Form] component.
+//! Both conversions can be generated using [FormModel] derive macro.
 //!
 //! See story book for examples.
 
-use std::rc::Rc;
-use vertigo::{AttrGroup, Computed, Css, Value, bind, bind_rc, component, css, dom};
+use std::{
+    cell::{Cell, RefCell},
+    collections::HashSet,
+    rc::Rc,
+};
+use vertigo::{
+    AttrGroup, Computed, Css, DomNode, Value, bind, bind_rc, component, css, dom, dom_element,
+    transaction,
+};
 
-use crate::{TabsParams, ValidationErrors};
+use crate::{TabsParams, ValidationErrors, locale, theme::var};
+
+mod async_form;
+pub use async_form::{AsyncForm, AsyncModelForm, AsyncSubmitFunc};
 
 mod data;
 pub use data::*;
 
+mod draft;
+use draft::{Draft, DraftLabels};
+
+mod history;
+pub use history::FormHistory;
+
+mod live_validation;
+use live_validation::LiveValidation;
+
 mod render;
 pub use render::*;
 
+pub use regex_lite::Regex;
+
+/// Generates `From<T> for FormData` and `From<FormExport> for T` for a struct with named fields.
+///
+/// Every field becomes a section with a single form field, unless grouped with other fields
+/// using `section` attribute. Field-level attributes (all optional):
+///
+/// * `label = "..."` - section label (defaults to capitalized field name),
+/// * `key = "..."` - key in [FormExport] (defaults to field name),
+/// * `kind = "..."` - one of `string` (default), `textarea`, `list`, `radio`, `dict`, `number`, `date`,
+///   `time`, `datetime`, `bool`, `image`, `group`, `nested` (`number` fields of `f32`/`f64` type accept
+///   decimals, others only integers; `nested` fields are another `FormModel`, `group` fields are `Vec`
+///   of another `FormModel` implementing `Default`),
+/// * `options = expr` - options for `list`, `radio` (`Vec<String>`) and `dict` (`Vec<(i64, String)>`) fields,
+/// * `rows = n`, `cols = n` - size of `textarea` field,
+/// * `section = "..."` - fields with the same section name are rendered in one section,
+///   the first one provides the label,
+/// * `fieldset = "plain" | "dimensions"` - [FieldsetStyle] of the section,
+/// * `tab = "..."` - put the section into a tab with this label (fields of one section
+///   can't be put into different tabs),
+/// * `skip` - don't render the field, it is restored using `Default::default()`.
+///
+/// Struct-level attributes: `top_controls`, `bottom_controls`.
+///
+/// `Option<T>` fields use optional variants of builders and getters
+/// (not supported for `dict`, `group` and `nested`). Other `date`, `time` and `datetime` fields
+/// fall back to default when empty, which is reported in [strict](FormExport::strict) export.
+///
+/// ```rust
+/// use std::collections::HashMap;
+/// use vertigo_forms::{date::Date, form::{FieldExport, FormData, FormExport, FormModel}};
+///
+/// #[derive(Clone, Default, PartialEq, FormModel)]
+/// #[form(bottom_controls)]
+/// struct Car {
+///     brand: String,
+///     #[form(label = "Engine", kind = "list", options = engine_types())]
+///     engine: String,
+///     #[form(label = "Dimensions", section = "dim", fieldset = "dimensions")]
+///     width: String,
+///     #[form(section = "dim")]
+///     height: String,
+///     #[form(kind = "number")]
+///     seats: u8,
+///     #[form(kind = "bool", tab = "Other")]
+///     used: bool,
+///     #[form(kind = "textarea", rows = 5, tab = "Other")]
+///     notes: Option<String>,
+///     #[form(kind = "date", tab = "Other")]
+///     registered: Option<Date>,
+///     #[form(kind = "date", tab = "Other")]
+///     produced: Date,
+///     #[form(kind = "group", tab = "Other")]
+///     owners: Vec<Owner>,
+///     #[form(skip)]
+///     id: u32,
+/// }
+///
+/// #[derive(Clone, Default, PartialEq, FormModel)]
+/// struct Owner {
+///     name: String,
+///     #[form(kind = "nested")]
+///     address: Address,
+/// }
+///
+/// #[derive(Clone, Default, PartialEq, FormModel)]
+/// struct Address {
+///     city: String,
+/// }
+///
+/// fn engine_types() -> Vec<String> {
+///     vec!["petrol".into(), "diesel".into()]
+/// }
+///
+/// let form_data = FormData::from(Car {
+///     brand: "Fiat".into(),
+///     owners: vec![Owner {
+///         name: "Tom".into(),
+///         address: Address { city: "Oslo".into() },
+///     }],
+///     id: 7,
+///     ..Default::default()
+/// });
+/// assert_eq!(form_data.sections.len(), 4);
+/// assert_eq!(form_data.tabs[0].0, "Other");
+/// assert_eq!(form_data.tabs[0].1.len(), 5);
+///
+/// let export = form_data.export().strict();
+/// let car = Car::from(export.clone());
+/// assert_eq!(car.brand, "Fiat");
+/// assert_eq!(car.seats, 0);
+/// assert_eq!(car.notes, None);
+/// assert_eq!(car.registered, None);
+/// assert_eq!(car.produced, Date::default());
+/// assert_eq!(car.owners[0].name, "Tom");
+/// assert_eq!(car.owners[0].address.city, "Oslo");
+/// assert_eq!(car.id, 0);
+/// assert!(export.errors().is_empty());
+///
+/// // Empty non-optional date is reported in strict export
+/// #[derive(Clone, FormModel)]
+/// struct Trip {
+///     #[form(kind = "date")]
+///     start: Date,
+/// }
+///
+/// let export = FormExport::new(HashMap::from([("start".to_string(), FieldExport::Date(None))]))
+///     .strict();
+/// assert_eq!(Trip::from(export.clone()).start, Date::default());
+/// assert_eq!(export.errors().get("start").unwrap(), "Missing field \"start\"");
+/// ```
+///
+/// Fields of one section can't be put into different tabs:
+///
+/// ```rust,compile_fail
+/// use vertigo_forms::form::FormModel;
+///
+/// #[derive(Clone, Default, FormModel)]
+/// struct Car {
+///     #[form(section = "dim", tab = "Size")]
+///     width: String,
+///     #[form(section = "dim", tab = "Other")]
+///     height: String,
+/// }
+/// ```
+pub use vertigo_forms_macro::FormModel;
+
 #[derive(Clone)]
 pub struct FormParams<T: 'static> {
+    /// Id of the form element, prepended to ids of fields (i.e. `profile-first_name`).
+    /// Generated if not provided.
+    pub id: Option<Rc<String>>,
     pub css: Css,
     pub add_css: Css,
     pub add_section_css: Css,
     pub submit_label: Rc<String>,
     pub on_delete: Option<Rc<dyn Fn()>>,
     pub delete_label: Rc<String>,
+    /// Label for reset control, which reverts all fields to original values (not rendered if `None`)
+    pub reset_label: Option<Rc<String>>,
     pub validate: Option<ValidateFunc<T>>,
+    /// Report missing keys and mismatched field kinds found during conversion to the model
+    /// as validation errors (see [FormExport::strict]).
+    pub strict_export: bool,
     pub validation_errors: Value<ValidationErrors>,
+    /// When validators (of fields and `validate` function) are run
+    pub validation_mode: ValidationMode,
+    /// State of saving shown next to controls. Form sets it to `Saving` when submitted with valid values,
+    /// further changes are left to the application (see [AsyncForm] for automatic handling).
+    /// While `Saving`, the submit control is disabled and submits are ignored.
     pub operation: Option<Value<Operation>>,
+    /// Time (in milliseconds) after which `Success` set by [AsyncForm] is cleared (kept if `None`)
+    pub success_clear_delay: Option<u32>,
     pub saving_label: Rc<String>,
     pub saved_label: Rc<String>,
     pub tabs_params: Option<TabsParams>,
+    /// Text appended to labels of sections with required fields (not rendered if `None`)
+    pub required_marker: Option<Rc<String>>,
+    /// Show help of fields (see [DataSection::help]) in a popup displayed when hovering an icon,
+    /// instead of inline text below the control
+    pub help_as_popup: bool,
+    /// How tabs of the form are presented
+    pub mode: FormMode,
+    pub next_label: Rc<String>,
+    pub back_label: Rc<String>,
+    /// Css of a badge with number of errors, shown in headers of tabs containing invalid fields
+    pub error_badge_css: Css,
+    /// Css added to sections with fields modified by user
+    pub dirty_section_css: Option<Css>,
+    /// Disable submit control while no field has been modified
+    pub submit_only_when_dirty: bool,
+    /// Keep unsaved values in browser's local storage under this key,
+    /// and offer to restore them when the form is mounted again. The draft is removed on submit.
+    pub draft_key: Option<Rc<String>>,
+    /// Drafts saved with a different version are discarded, bump it when structure of the form changes
+    pub draft_version: u32,
+    /// Delay (in milliseconds) after the last change before the draft is saved
+    pub draft_debounce: u32,
+    pub draft_found_label: Rc<String>,
+    pub draft_restore_label: Rc<String>,
+    pub draft_discard_label: Rc<String>,
+    /// Record undo/redo history of changes in the form
+    pub history: Option<FormHistory>,
 }
 
 impl<T: 'static> Default for FormParams<T> {
     fn default() -> Self {
+        let locale = locale();
+        let label = |text: &String| Rc::new(text.clone());
         Self {
+            id: None,
             css: css! { "
                 display: grid;
                 grid-template-rows: auto 1fr;
                 gap: 5px;
+                font-family: {var::FONT_FAMILY};
             " },
             add_css: Css::default(),
             add_section_css: Css::default(),
-            submit_label: Rc::new("Submit".to_string()),
+            submit_label: label(&locale.submit),
             on_delete: None,
-            delete_label: Rc::new("Delete".to_string()),
+            delete_label: label(&locale.delete),
+            reset_label: None,
             validate: None,
+            strict_export: false,
             validation_errors: Default::default(),
+            validation_mode: Default::default(),
             operation: Default::default(),
-            saving_label: Rc::new("Saving...".to_string()),
-            saved_label: Rc::new("Saved".to_string()),
+            success_clear_delay: Some(3000),
+            saving_label: label(&locale.saving),
+            saved_label: label(&locale.saved),
             tabs_params: None,
+            required_marker: Some(Rc::new("*".to_string())),
+            help_as_popup: false,
+            mode: Default::default(),
+            next_label: label(&locale.next),
+            back_label: label(&locale.back),
+            error_badge_css: css! {"
+                display: inline-block;
+                min-width: 1em;
+                margin-left: 4px;
+                padding: 0 4px;
+                border-radius: 8px;
+                background-color: {var::DANGER};
+                color: {var::ON_ACCENT};
+                font-size: 0.75em;
+                text-align: center;
+            "},
+            dirty_section_css: None,
+            submit_only_when_dirty: false,
+            draft_key: None,
+            draft_version: 0,
+            draft_debounce: 500,
+            draft_found_label: label(&locale.draft_found),
+            draft_restore_label: label(&locale.draft_restore),
+            draft_discard_label: label(&locale.draft_discard),
+            history: None,
         }
     }
 }
 
+/// Moment when the form gets validated.
+///
+/// In live modes, every change of field values re-runs field validators and
+/// [FormParams::validate] function, but errors are shown only for fields the user has already
+/// interacted with (or for all fields after first submit).
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum ValidationMode {
+    /// Validate only when the form is submitted
+    #[default]
+    OnSubmit,
+    /// Validate live, show error for a field after it loses focus
+    OnBlur,
+    /// Validate live, show error for a field after it gets modified
+    OnChange,
+    /// Validate on submit, then live after first submit
+    OnSubmitThenChange,
+}
+
+/// Presentation of tabs of the form (see [FormData::add_tab]).
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum FormMode {
+    /// Tabs can be switched freely
+    #[default]
+    Tabs,
+    /// Tabs are consecutive steps with Next and Back controls, fields of a step are validated
+    /// before moving to the next one and the form is submitted only from the last step.
+    /// Progress indicator is styled using [FormParams::tabs_params].
+    /// Sections outside of tabs are shown on every step.
+    Wizard,
+}
+
 /// Renders a form for provided model, that upon "Save" allows to update a model with new values.
 ///
 /// A model needs to implement conversion to [FormData] and from [FormExport] to interoperate with this component.
@@ -118,7 +371,96 @@ pub fn Form<T>(
         grid-column: span 2 / span 2;
     "};
 
+    let form_id = params
+        .id
+        .as_ref()
+        .map(|id| id.to_string())
+        .unwrap_or_else(generate_form_id);
     let validation_errors = params.validation_errors.clone();
+    let live_validation = LiveValidation::new(params.validation_mode);
+
+    let is_saving = {
+        let operation = params.operation.clone();
+        Computed::from(move |ctx| {
+            operation
+                .as_ref()
+                .is_some_and(|operation| operation.get(ctx) == Operation::Saving)
+        })
+    };
+
+    let submit_disabled = {
+        let is_dirty = params.submit_only_when_dirty.then(|| form_data.is_dirty());
+        let is_validation_pending = form_data.is_validation_pending();
+        let is_saving = is_saving.clone();
+        Computed::from(move |ctx| {
+            let unchanged = is_dirty.as_ref().is_some_and(|dirty| !dirty.get(ctx));
+            (unchanged || is_validation_pending.get(ctx) || is_saving.get(ctx))
+                .then(|| "disabled".to_string())
+        })
+    };
+
+    // Current step in wizard mode
+    let step =
+        (params.mode == FormMode::Wizard && !form_data.tabs.is_empty()).then(|| Value::new(0));
+    let last_step = form_data.tabs.len().saturating_sub(1);
+    // Current tab in tabs mode
+    let current_tab = Value::new(
+        form_data
+            .tabs
+            .first()
+            .map(|(label, _)| label.clone())
+            .unwrap_or_default(),
+    );
+
+    // Switch to the first tab (or step) containing invalid fields
+    let show_errors = bind_rc!(form_data, step, current_tab, |errors: &ValidationErrors| {
+        let Some(idx) = first_tab_with_errors(&form_data.tabs, errors) else {
+            return;
+        };
+        match &step {
+            Some(step) => step.set(idx),
+            None => current_tab.set(form_data.tabs[idx].0.clone()),
+        }
+    });
+
+    // Replace errors of field validators and these set by previous submit,
+    // keeping errors set by the application
+    let submit_error_keys = Rc::new(RefCell::new(HashSet::<String>::new()));
+    let set_submit_errors = bind_rc!(
+        form_data,
+        validation_errors,
+        submit_error_keys,
+        |errors: ValidationErrors| {
+            let previous = submit_error_keys.replace(errors.keys().cloned().collect());
+            validation_errors.change(|all_errors| {
+                all_errors
+                    .retain(|key, _| !previous.contains(key) && !form_data.is_field_error_key(key));
+                all_errors.extend(errors);
+            });
+        }
+    );
+
+    // Validate fields of current step and move to the next one
+    let next_step = step.clone().map(|step| {
+        bind_rc!(form_data, validation_errors, || {
+            let current = transaction(|ctx| step.get(ctx));
+            let Some((_, sections)) = form_data.tabs.get(current) else {
+                return;
+            };
+            let in_step = |key: &String| is_error_in(sections, key);
+
+            let mut errors = form_data.validate_fields();
+            errors.retain(|key, _| in_step(key));
+            let valid = errors.is_empty();
+            validation_errors.change(|all_errors| {
+                all_errors.retain(|key, _| !in_step(key));
+                all_errors.extend(errors);
+            });
+            if valid {
+                step.set((current + 1).min(last_step));
+            }
+        })
+    });
 
     let controls = |params: &FormParams<T>, c_config: &ControlsConfig| {
         let mut controls = vec![];
@@ -128,9 +470,45 @@ pub fn Form<T>(
         "};
 
         if c_config.submit {
-            controls.push(dom! {
-                <input css={&ctrl_item_css} type="submit" value={&params.submit_label} />
-            });
+            let submit = {
+                let ctrl_item_css = ctrl_item_css.clone();
+                let submit_label = params.submit_label.clone();
+                let submit_disabled = submit_disabled.clone();
+                move || {
+                    dom! {
+                        <input
+                            css={&ctrl_item_css}
+                            type="submit"
+                            value={&submit_label}
+                            disabled={&submit_disabled}
+                        />
+                    }
+                }
+            };
+            match (&step, &next_step) {
+                (Some(step), Some(next_step)) => {
+                    let on_back =
+                        bind!(step, |_| step.change(|step| *step = step.saturating_sub(1)));
+                    let back_disabled =
+                        step.map(|step| (step == 0).then(|| "disabled".to_string()));
+                    controls.push(dom! {
+                        <input css={&ctrl_item_css} type="button" value={&params.back_label} disabled={back_disabled} on_click={on_back} />
+                    });
+                    let next_label = params.next_label.clone();
+                    let ctrl_item_css = ctrl_item_css.clone();
+                    controls.push(step.render_value(bind!(next_step, |step| {
+                        if step == last_step {
+                            submit()
+                        } else {
+                            let on_click = bind!(next_step, |_| next_step());
+                            dom! {
+                                <input css={&ctrl_item_css} type="button" value={&next_label} {on_click} />
+                            }
+                        }
+                    })));
+                }
+                _ => controls.push(submit()),
+            }
         }
         if c_config.delete
             && let Some(on_click) = params.on_delete.clone()
@@ -139,6 +517,20 @@ pub fn Form<T>(
                 <input css={&ctrl_item_css} type="submit" value={&params.delete_label} on_click={move |_| on_click()} />
             });
         }
+        if c_config.reset
+            && let Some(reset_label) = &params.reset_label
+        {
+            let on_click = bind!(form_data, validation_errors, live_validation, |_| {
+                transaction(|_| {
+                    form_data.reset();
+                    live_validation.restart(&form_data);
+                    validation_errors.set(Default::default());
+                });
+            });
+            controls.push(dom! {
+                <input css={&ctrl_item_css} type="button" value={reset_label} {on_click} />
+            });
+        }
 
         let errors = validation_errors
             .render_value_option(|errs| errs.get("submit").map(|err| dom! { <span>{err}</span> }));
@@ -153,7 +545,7 @@ pub fn Form<T>(
                         Operation::Saving => Some(saving_label.clone()),
                         Operation::Success => Some(saved_label.clone()),
                         Operation::Error(err) => {
-                            css += css! {"color: red;"};
+                            css += css! {"color: {var::DANGER};"};
                             Some(err)
                         }
                         _ => None,
@@ -173,8 +565,10 @@ pub fn Form<T>(
             Some(dom! {
                 <div css={css_controls}>
                     {..controls}
-                    {errors}
-                    {..operation_str}
+                    <span role="status" aria-live="polite">
+                        {errors}
+                        {..operation_str}
+                    </span>
                 </div>
             })
         }
@@ -183,48 +577,154 @@ pub fn Form<T>(
     let top_controls = controls(&params, &form_data.top_controls);
     let bottom_controls = controls(&params, &form_data.bottom_controls);
 
-    let section_css = subgrid_css + params.add_section_css;
+    let sections_ctx = SectionsContext {
+        s,
+        validation_errors: validation_errors.clone(),
+        section_css: subgrid_css + params.add_section_css.clone(),
+        dirty_section_css: params.dirty_section_css.clone(),
+        blurred: live_validation.blurred.clone(),
+        prefix: String::new(),
+        values: Some(Computed::from(
+            bind!(form_data, |ctx| form_data.export_all_in(ctx))
+        )),
+        id: form_id.clone(),
+        required_marker: params.required_marker.clone(),
+        help_popup: params.help_as_popup,
+    };
+
+    let fields = fields(&form_data.sections, &sections_ctx);
 
-    let fields = fields(
-        &form_data.sections,
-        &s,
-        validation_errors.clone(),
-        &section_css,
-    );
+    let tabs = match &step {
+        Some(step) => wizard(
+            &form_data.tabs,
+            &params.tabs_params,
+            &sections_ctx,
+            &params.css,
+            step,
+            &params.error_badge_css,
+        ),
+        None => tabs(
+            &form_data.tabs,
+            &params.tabs_params,
+            &sections_ctx,
+            &params.css.clone(),
+            &current_tab,
+            &params.error_badge_css,
+        ),
+    };
 
-    let tabs = tabs(
-        &form_data.tabs,
-        &params.tabs_params,
-        &s,
-        validation_errors.clone(),
-        &section_css,
-        &params.css.clone(),
-    );
+    let form_css = params.css.clone() + params.add_css.clone();
+
+    let live_validation_subscription =
+        live_validation.subscribe(&form_data, params.validate.clone(), &validation_errors);
 
-    let form_css = params.css + params.add_css;
+    let is_validation_pending = form_data.is_validation_pending();
 
-    let on_submit = bind_rc!(form_data, validation_errors, || {
-        params
-            .operation
-            .as_ref()
-            .inspect(|operation| operation.set(Operation::Saving));
+    let draft = params
+        .draft_key
+        .clone()
+        .map(|key| Draft::new(key, params.draft_version, params.draft_debounce));
+    let draft_offer = draft.as_ref().map(|draft| {
+        draft.render(
+            &form_data,
+            DraftLabels {
+                found: params.draft_found_label.clone(),
+                restore: params.draft_restore_label.clone(),
+                discard: params.draft_discard_label.clone(),
+            },
+        )
+    });
+    let draft_subscription = draft.as_ref().map(|draft| draft.subscribe(&form_data));
+    let history_subscription = params
+        .history
+        .as_ref()
+        .map(|history| history.attach(&form_data));
+
+    let on_submit = bind_rc!(form_data, validation_errors, live_validation, draft, || {
+        // Ignore submits (i.e. using Enter key) while checking or saving previous ones
+        if transaction(|ctx| is_validation_pending.get(ctx) || is_saving.get(ctx)) {
+            return;
+        }
+        // Submitting (i.e. using Enter key) before the last step of wizard moves to the next step
+        if let (Some(step), Some(next_step)) = (&step, &next_step)
+            && transaction(|ctx| step.get(ctx)) < last_step
+        {
+            next_step();
+            return;
+        }
+        live_validation.submitted.set(true);
         let model = form_data.export();
+        if params.strict_export {
+            let strict_model = model.clone().strict();
+            let _ = T::from(strict_model.clone());
+            let errors = strict_model.errors();
+            if !errors.is_empty() {
+                show_errors(&errors);
+                set_submit_errors(errors);
+                return;
+            }
+        }
+        let field_errors = form_data.validate_fields();
+        if !field_errors.is_empty() {
+            show_errors(&field_errors);
+            set_submit_errors(field_errors);
+            return;
+        }
+        // Clear errors left from previous submit
+        set_submit_errors(Default::default());
         let valid = if let Some(validate) = &params.validate {
             validate(&model.clone().into(), validation_errors.clone())
         } else {
             true
         };
         if valid {
+            if let Some(draft) = &draft {
+                draft.clear();
+            }
+            params
+                .operation
+                .as_ref()
+                .inspect(|operation| operation.set(Operation::Saving));
             on_submit(model);
+        } else {
+            show_errors(&transaction(|ctx| validation_errors.get(ctx)));
         }
     });
 
-    dom! {
-        <form css={form_css} on_submit={on_submit} {..f}>
+    let mut form = dom_element! {
+        <form id={form_id} css={form_css} on_submit={on_submit} {..f}>
+            {..draft_offer}
             {..top_controls}
             {..fields}
             {..tabs}
             {..bottom_controls}
         </form>
+    };
+
+    if let Some(subscription) = live_validation_subscription {
+        form.append_drop_resource(subscription);
+    }
+    if let Some(subscription) = draft_subscription {
+        form.append_drop_resource(subscription);
+    }
+    if let Some(subscription) = history_subscription {
+        form.append_drop_resource(subscription);
+    }
+    if let Some(history) = params.history.clone().filter(|history| history.shortcuts) {
+        form = form.on_key_down(move |event| history.on_key_down(event));
     }
+
+    DomNode::from(form)
+}
+
+/// Unique id for a form rendered without [FormParams::id].
+fn generate_form_id() -> String {
+    thread_local! {
+        static NEXT_ID: Cell<u32> = const { Cell::new(1) };
+    }
+    NEXT_ID.with(|next_id| {
+        let id = next_id.get();
+        next_id.set(id + 1);
+        format!("form{id}")
+    })
 }
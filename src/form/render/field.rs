@@ -1,21 +1,42 @@
-use vertigo::{DomElement, Value, bind, component, css, dom, dom_element};
+use vertigo::{AttrGroup, DomElement, DomNode, Value, bind, component, css, dom, dom_element};
 
-use crate::{DictSelect, DropImageFile, Select, SelectSearch, Switch, SwitchParams, input::Input};
+use crate::{
+    CalendarParams, DatePicker, DatePickerParams, DateTimePicker, DictRadioGroup, DictSelect,
+    DropImageFile, RadioGroup, SelectSearch, Switch, SwitchParams,
+    date::{Date, DateFormat, Time},
+    date_picker::parsed_input,
+    input::Input,
+    locale,
+    select::render_select,
+};
 
-use super::super::{DataField, DataFieldValue};
+use super::{
+    super::{DataField, DataFieldValue, DateFieldKind, NumberMode, RadioValue},
+    SectionsContext,
+    group::group,
+    nested::nested,
+};
 
+/// Renders control for a single [DataField].
+///
+/// Use `i` attribute group to pass anything (i. e. `on_blur`) to underlying control element.
+/// Controls made of many elements (radio, multi, group and nested fields) get `id` and `aria-*`
+/// attributes on their container, with the rest passed to their inputs.
+/// Custom fields get attributes on their root element, computed fields ignore them.
+///
+/// Validation errors of fields in group entries and nested forms, as well as computed fields
+/// (which need values of other fields) are rendered only within a [Form](super::super::Form).
 #[component]
-pub fn Field<'a>(field: &'a DataField) {
+pub fn Field<'a>(field: &'a DataField, i: AttrGroup) {
     match &field.value {
         DataFieldValue::String(val) => {
-            dom! { <Input input:name={&&field.key} value={val.value.clone()} /> }
+            dom! { <Input input:name={&&field.key} input={..i} value={val.value.clone()} /> }
         }
         DataFieldValue::TextArea(val) => {
             let on_input = bind!(val.value, |new_value: String| {
                 value.set(new_value);
             });
-            let el =
-                dom_element! { <textarea name={&&field.key} {on_input}>{&val.value}</textarea> };
+            let el = dom_element! { <textarea name={&&field.key} {on_input} {..i}>{&val.value}</textarea> };
             if let Some(rows) = val.rows {
                 el.add_attr("rows", rows);
             }
@@ -25,20 +46,97 @@ pub fn Field<'a>(field: &'a DataField) {
             el.into()
         }
         DataFieldValue::List(val) => {
-            dom! { <Select value={val.value.clone()} options={&val.options} /> }
+            let mut attrs = i;
+            attrs.insert("name".to_string(), field.key.clone().into());
+            render_select(&val.value, &val.options, attrs)
+        }
+        DataFieldValue::Number(val) => {
+            let on_input = bind!(val.value, |new_value: String| {
+                value.set(new_value);
+            });
+            let el = dom_element! {
+                <input type="number" name={&&field.key} value={&val.value} {on_input} {..i} />
+            };
+            let params = &val.params;
+            if let Some(min) = params.min {
+                el.add_attr("min", min);
+            }
+            if let Some(max) = params.max {
+                el.add_attr("max", max);
+            }
+            match (params.step, params.mode) {
+                (Some(step), _) => el.add_attr("step", step),
+                (None, NumberMode::Integer) => el.add_attr("step", 1),
+                (None, NumberMode::Decimal) => el.add_attr("step", "any"),
+            }
+            el.into()
+        }
+        DataFieldValue::Date(val) => {
+            let params = picker_params(&val.params.format, val.params.min, val.params.max);
+            dom! {
+                <DatePicker i:name={&&field.key} i={..i} value={val.value.clone()} {params} />
+            }
+        }
+        DataFieldValue::Time(val) => {
+            let mut i = i;
+            i.insert("name".to_string(), field.key.clone().into());
+            let (format_fmt, format_parse) = (val.params.format.clone(), val.params.format.clone());
+            parsed_input(
+                &val.value,
+                move |time: &Time| time.format(&format_fmt),
+                move |text| Time::parse(text, &format_parse),
+                i,
+            )
+        }
+        DataFieldValue::DateTime(val) => {
+            let params = picker_params(
+                &val.params.format,
+                val.params.min.map(|min| min.date()),
+                val.params.max.map(|max| max.date()),
+            );
+            dom! {
+                <DateTimePicker i:name={&&field.key} i={..i} value={val.value.clone()} {params} />
+            }
+        }
+        DataFieldValue::Radio(RadioValue::List(val, params)) => {
+            let (g, i) = split_attrs(i, "radiogroup");
+            dom! {
+                <RadioGroup
+                    i:name={&&field.key}
+                    i={..i}
+                    g={..g}
+                    value={val.value.clone()}
+                    options={&val.options}
+                    params={params.clone()}
+                />
+            }
+        }
+        DataFieldValue::Radio(RadioValue::Dict(val, params)) => {
+            let (g, i) = split_attrs(i, "radiogroup");
+            dom! {
+                <DictRadioGroup
+                    i:name={&&field.key}
+                    i={..i}
+                    g={..g}
+                    value={val.value.clone()}
+                    options={&val.options}
+                    params={params.clone()}
+                />
+            }
         }
         DataFieldValue::Bool(val) => {
             dom! {
-                <Switch i:name={&&field.key} value={&val.value} params={SwitchParams::checkbox()} />
+                <Switch i:name={&&field.key} i={..i} value={&val.value} params={SwitchParams::checkbox()} />
             }
         }
         DataFieldValue::Dict(val) => {
-            dom! { <DictSelect value={val.value.clone()} options={&val.options} /> }
+            dom! { <DictSelect value={val.value.clone()} options={&val.options} select:name={&&field.key} select={..i} /> }
         }
         DataFieldValue::Multi(val) => {
+            let (g, i) = split_attrs(i, "group");
             bind!(val.value, val.options, val.add_label,
                 val.value.render_value(move |vals| {
-                    let selects = DomElement::new("div");
+                    let selects = DomElement::new("div").add_attr_group(g.clone());
                     let row_css = css! {"
                         display: flex;
                     "};
@@ -49,8 +147,10 @@ pub fn Field<'a>(field: &'a DataField) {
                         }));
                         selects.add_child(dom! {
                         <div css={&row_css}>
-                            <SelectSearch value={tag_id.clone()} options={options.clone()} params={} />
-                            <button {on_click}>"x"</button>
+                            <SelectSearch value={tag_id.clone()} options={options.clone()} params={} i={..i.clone()} />
+                            <button type="button" aria-label={&locale().remove} {on_click}>
+                                <span aria-hidden="true">"×"</span>
+                            </button>
                         </div>
                     });
                     }
@@ -73,9 +173,43 @@ pub fn Field<'a>(field: &'a DataField) {
                 item={val.value.clone()}
                 original_link={val.original_link.clone()}
                 {params}
+                zone={..i}
             /> }
         }
-        DataFieldValue::Custom(val) => (val.render)(),
-        DataFieldValue::StaticCustom(render) => render(),
+        DataFieldValue::Group(val) => group(&field.key, val, &SectionsContext::default(), i),
+        DataFieldValue::Nested(val) => nested(&field.key, val, &SectionsContext::default(), i),
+        DataFieldValue::Computed(_) => dom! { <span /> },
+        DataFieldValue::Custom(val) => with_attrs((val.render)(), i),
+        DataFieldValue::StaticCustom(render) => with_attrs(render(), i),
+    }
+}
+
+/// Splits attributes into these describing the whole control (`id` and `aria-*`),
+/// with provided `role` added, and the rest, meant for its inputs.
+fn split_attrs(attrs: AttrGroup, role: &str) -> (AttrGroup, AttrGroup) {
+    let (mut container, inputs): (AttrGroup, AttrGroup) = attrs
+        .into_iter()
+        .partition(|(key, _)| key == "id" || key.starts_with("aria-"));
+    container.insert("role".to_string(), role.into());
+    (container, inputs)
+}
+
+/// Sets attributes on the root element of custom control, wrapping it if it's not an element.
+fn with_attrs(node: DomNode, attrs: AttrGroup) -> DomNode {
+    match node {
+        DomNode::Node { node } => node.add_attr_group(attrs).into(),
+        node => dom! { <div {..attrs}>{node}</div> },
+    }
+}
+
+fn picker_params(format: &DateFormat, min: Option<Date>, max: Option<Date>) -> DatePickerParams {
+    DatePickerParams {
+        calendar: CalendarParams {
+            format: format.clone(),
+            min,
+            max,
+            ..Default::default()
+        },
+        ..Default::default()
     }
 }
@@ -1,14 +1,22 @@
-use vertigo::{AttrGroup, Css, DomNode, Value, css, dom};
+use vertigo::{
+    AttrGroup, AttrGroupValue, AttrValue, Computed, DomNode, bind, css, dom, dom_element,
+};
 
-use crate::ValidationErrors;
+use crate::{PopupOnHover, PopupParams, locale, theme::var};
 
-use super::super::{DataField, DataSection, Field, FieldsetStyle};
+use super::{
+    super::{
+        ComputedValue, DataField, DataFieldValue, DataSection, Field, FieldsetStyle, FormData,
+        SectionCondition,
+    },
+    SectionsContext,
+    group::group,
+    nested::nested,
+};
 
 pub(in super::super) fn fields<'a>(
     sections: &'a [DataSection],
-    s: &'a AttrGroup,
-    validation_errors: Value<ValidationErrors>,
-    section_css: &'a Css,
+    ctx: &'a SectionsContext,
 ) -> impl Iterator<Item = DomNode> + 'a {
     let fieldset_flex_css = css! {"
         display: flex;
@@ -16,35 +24,104 @@ pub(in super::super) fn fields<'a>(
     "};
 
     sections.iter().flat_map(move |section| {
-        let attrs = s.clone();
+        let attrs = ctx.s.clone();
         let custom_fieldset_css = section.fieldset_css.clone().unwrap_or_else(|| css! {""});
 
-        let section_rendered = if section.fields.len() > 1 {
+        let base_section_css = match &ctx.dirty_section_css {
+            Some(dirty_section_css) => {
+                let (section_css, dirty_section_css) =
+                    (ctx.section_css.clone(), dirty_section_css.clone());
+                section.is_dirty().map(move |dirty| {
+                    if dirty {
+                        &section_css + &dirty_section_css
+                    } else {
+                        section_css.clone()
+                    }
+                })
+            }
+            None => Computed::from({
+                let section_css = ctx.section_css.clone();
+                move |_| section_css.clone()
+            }),
+        };
+
+        // Hidden sections are kept in DOM, so fields preserve their state
+        let section_css = match condition(&section.visible_when, ctx) {
+            Some(visible) => Computed::from(move |ctx| {
+                let css = base_section_css.get(ctx);
+                if visible.get(ctx) {
+                    css
+                } else {
+                    &css + &css! {"display: none;"}
+                }
+            }),
+            None => base_section_css,
+        };
+
+        let content = if section.fields.len() > 1 {
             let mut values = vec![];
             for (i, field) in section.fields.iter().enumerate() {
                 if section.fieldset_style == FieldsetStyle::Dimensions && i > 0 {
                     values.push(dom! { <span>"x"</span> });
                 }
-                values.push(render_field(field, &validation_errors));
+                values.push(render_field(field, ctx));
             }
 
-            dom! {
-                <label css={section_css} {..attrs}>
-                    {&section.label}
-                    <div css={&fieldset_flex_css} css={custom_fieldset_css}>
-                        {..values}
-                    </div>
-                </label>
-            }
-        } else if let Some(field) = section.fields.first() {
-            dom! {
-                <label css={section_css} {..attrs}>
-                    {&section.label}
-                    {render_field(field, &validation_errors)}
-                </label>
-            }
+            Some(dom! {
+                <div css={&fieldset_flex_css} css={custom_fieldset_css}>
+                    {..values}
+                </div>
+            })
         } else {
-            dom! { <p /> }
+            section.fields.first().map(|field| render_field(field, ctx))
+        };
+
+        // Disabled fieldset disables all controls inside
+        let content = match condition(&section.enabled_when, ctx) {
+            Some(enabled) => content.map(|content| {
+                let disabled = enabled.map(|enabled| (!enabled).then(|| "disabled".to_string()));
+                dom! {
+                    <fieldset
+                        css={css! {"border: none; margin: 0; padding: 0; min-width: 0;"}}
+                        {disabled}
+                    >
+                        {content}
+                    </fieldset>
+                }
+            }),
+            None => content,
+        };
+
+        // Label would pass clicks to the first control of a group or nested form
+        let has_group = section.fields.iter().any(|field| {
+            matches!(
+                field.value,
+                DataFieldValue::Group(_) | DataFieldValue::Nested(_)
+            )
+        });
+
+        // Marker is decorative, controls have `aria-required` set
+        let required_marker = ctx
+            .required_marker
+            .clone()
+            .filter(|_| section.fields.iter().any(|field| field.is_required()))
+            .map(|marker| dom! { <span aria-hidden="true">" "{marker}</span> });
+        let tooltip = section.tooltip.as_ref().map(|render| hint_icon(render()));
+
+        let section_rendered = match content {
+            Some(content) if has_group => dom! {
+                <div css={section_css.clone()} role="group" {..attrs}>
+                    <span>{&section.label}{..required_marker}{..tooltip}</span>
+                    {content}
+                </div>
+            },
+            Some(content) => dom! {
+                <label css={section_css.clone()} {..attrs}>
+                    <span>{&section.label}{..required_marker}{..tooltip}</span>
+                    {content}
+                </label>
+            },
+            None => dom! { <p /> },
         };
 
         if section.new_group {
@@ -58,17 +135,185 @@ pub(in super::super) fn fields<'a>(
     })
 }
 
-fn render_field(field: &DataField, validation_errors: &Value<ValidationErrors>) -> DomNode {
+/// Reactive result of section condition, if there is one and values are known.
+fn condition(
+    condition: &Option<SectionCondition>,
+    ctx: &SectionsContext,
+) -> Option<Computed<bool>> {
+    let condition = condition.clone()?;
+    let values = ctx.values.as_ref()?;
+    Some(values.map(move |values| condition(&values)))
+}
+
+/// Read-only text following current values of the form.
+fn computed(val: &ComputedValue, ctx: &SectionsContext) -> DomNode {
+    let text = match &ctx.values {
+        Some(values) => {
+            let compute = val.compute.clone();
+            values.map(move |values| compute(&values))
+        }
+        None => Computed::from(|_| String::new()),
+    };
+    dom! { <span css={css! {"padding: 2px 0;"}}>{text}</span> }
+}
+
+/// Icon showing provided content in a popup when hovered.
+fn hint_icon(content: DomNode) -> DomNode {
+    let icon_css = css! {"
+        display: inline-block;
+        width: 1.2em;
+        margin-left: 4px;
+        border: 1px solid;
+        border-color: {var::TEXT_MUTED};
+        border-radius: 50%;
+        color: {var::TEXT_MUTED};
+        font-size: 0.8em;
+        line-height: 1.2em;
+        text-align: center;
+        cursor: help;
+    "};
+    let params = PopupParams {
+        css: css! {"
+            max-width: 250px;
+            padding: {var::SPACING_SM} {var::SPACING_MD};
+            border: 1px solid;
+            border-color: {var::BORDER};
+            border-radius: {var::RADIUS_SM};
+            background-color: {var::BACKGROUND};
+        "},
+    };
+    dom! {
+        <PopupOnHover
+            element={dom! { <span css={icon_css} aria-hidden="true">"?"</span> }}
+            {content}
+            {params}
+        />
+    }
+}
+
+/// Fields of all sections of a form embedded in another one, tabs are flattened.
+pub(super) fn form_fields(form_data: &FormData, ctx: &SectionsContext) -> Vec<DomNode> {
+    form_data
+        .tabs
+        .iter()
+        .flat_map(|(_, sections)| fields(sections, ctx).collect::<Vec<_>>())
+        .chain(fields(&form_data.sections, ctx))
+        .collect()
+}
+
+fn render_field(field: &DataField, ctx: &SectionsContext) -> DomNode {
+    let path = format!("{}{}", ctx.prefix, field.key);
+    let field_id = ctx.field_id(&path);
+    let error_id = format!("{field_id}-error");
+    let help_id = format!("{field_id}-help");
+
+    let invalid = {
+        let field_key = path.clone();
+        let validation_errors = ctx.validation_errors.clone();
+        let async_error = field
+            .async_validator
+            .as_ref()
+            .map(|async_validator| async_validator.error.clone());
+        Computed::from(move |ctx| {
+            let invalid = validation_errors.get(ctx).contains_key(&field_key)
+                || async_error
+                    .as_ref()
+                    .is_some_and(|async_error| async_error.get(ctx).is_some());
+            invalid.then(|| "true".to_string())
+        })
+    };
+
     let val_error = {
-        let field_key = field.key.to_owned();
-        validation_errors.render_value_option(move |errs| {
-            errs.get(&field_key).map(|err| dom! { <span>{err}</span> })
+        let field_key = path.clone();
+        let async_error = field
+            .async_validator
+            .as_ref()
+            .map(|async_validator| async_validator.error.clone());
+        ctx.validation_errors.render_value_option(move |errs| {
+            errs.get(&field_key)
+                .cloned()
+                .map(|err| dom! { <span>{err}</span> })
+                .or_else(|| {
+                    async_error.as_ref().map(|async_error| {
+                        async_error
+                            .render_value_option(|err| err.map(|err| dom! { <span>{err}</span> }))
+                    })
+                })
         })
     };
-    dom! {
+
+    let mut field_attrs = AttrGroup::new();
+    field_attrs.insert("id".to_string(), field_id.into());
+    field_attrs.insert(
+        "aria-invalid".to_string(),
+        AttrGroupValue::AttrValue(AttrValue::ComputedOpt(invalid)),
+    );
+    let described_by = match field.help {
+        Some(_) => format!("{error_id} {help_id}"),
+        None => error_id.clone(),
+    };
+    field_attrs.insert("aria-describedby".to_string(), described_by.into());
+    if let Some(placeholder) = &field.placeholder
+        && matches!(
+            field.value,
+            DataFieldValue::String(_)
+                | DataFieldValue::TextArea(_)
+                | DataFieldValue::Number(_)
+                | DataFieldValue::Date(_)
+                | DataFieldValue::Time(_)
+                | DataFieldValue::DateTime(_)
+        )
+    {
+        field_attrs.insert("placeholder".to_string(), placeholder.clone().into());
+    }
+    if field.is_required() {
+        field_attrs.insert("aria-required".to_string(), "true".into());
+    }
+    if let Some(blurred) = &ctx.blurred {
+        let on_blur = bind!(blurred, path, || blurred.change(|blurred| {
+            blurred.insert(path.clone());
+        }));
+        field_attrs.insert("on_blur".to_string(), AttrGroupValue::on_blur(on_blur));
+    }
+
+    let control = match &field.value {
+        DataFieldValue::Group(val) => group(&field.key, val, ctx, field_attrs),
+        DataFieldValue::Nested(val) => nested(&field.key, val, ctx, field_attrs),
+        DataFieldValue::Computed(val) => computed(val, ctx),
+        _ => dom! { <Field {field} i={..field_attrs} /> },
+    };
+
+    let help = field.help.as_ref().map(|help| {
+        let help_css = css! {"color: {var::TEXT_MUTED}; font-size: 0.85em;"};
+        dom! { <span id={help_id} css={help_css}>{help}</span> }
+    });
+    let (control, help) = match help {
+        Some(help) if ctx.help_popup => (
+            dom! {
+                <div css={css! {"display: flex; align-items: center; gap: 4px;"}}>
+                    {control}
+                    {hint_icon(help)}
+                </div>
+            },
+            None,
+        ),
+        help => (control, help),
+    };
+
+    let container = dom_element! {
         <div css={css! {"display: flex; flex-flow: column nowrap;"}}>
-            <Field {field} />
-            <span css={css! {"color: red;"}}>{val_error}</span>
+            {control}
+            {..help}
+            <span id={error_id} css={css! {"color: {var::DANGER};"}} aria-live="polite">{val_error}</span>
         </div>
+    };
+
+    if let Some(async_validator) = &field.async_validator {
+        let pending = async_validator.pending.render_value_option(|pending| {
+            pending.then(|| dom! { <span css={css! {"color: {var::TEXT_MUTED};"}}>{&locale().checking}</span> })
+        });
+        container.add_child(pending);
     }
+
+    container.into()
 }
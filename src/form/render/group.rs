@@ -0,0 +1,84 @@
+use vertigo::{AttrGroup, Computed, DomNode, bind, css, dom, dom_element};
+
+use super::{super::GroupValue, SectionsContext, fields::form_fields};
+
+/// Renders entries of a group field, each with controls to move and remove it.
+///
+/// Attributes (i.e. `id` and `aria-*`) are set on the container of entries.
+pub(super) fn group(
+    key: &str,
+    val: &GroupValue,
+    ctx: &SectionsContext,
+    attrs: AttrGroup,
+) -> DomNode {
+    let path = format!("{}{key}", ctx.prefix);
+    let ctx = ctx.clone();
+    let group = val.clone();
+
+    val.value.render_value(move |entries| {
+        let params = &group.params;
+        let entry_row_css = css! {"
+            display: flex;
+            gap: 5px;
+            align-items: flex-start;
+        "};
+        let count = entries.len();
+
+        let rows = entries
+            .iter()
+            .enumerate()
+            .map(|(idx, entry)| {
+                let entry_ctx = SectionsContext {
+                    prefix: format!("{path}.{idx}."),
+                    values: Some(Computed::from({
+                        let entry = entry.clone();
+                        move |ctx| entry.export_all_in(ctx)
+                    })),
+                    ..ctx.clone()
+                };
+                let fields = form_fields(entry, &entry_ctx);
+
+                let on_up = bind!(group, |_| group.move_entry(idx, idx.saturating_sub(1)));
+                let on_down = bind!(group, |_| group.move_entry(idx, idx + 1));
+                let on_remove = bind!(group, |_| group.remove(idx));
+
+                let up = dom_element! {
+                    <button type="button" on_click={on_up}>{&params.move_up_label}</button>
+                };
+                if idx == 0 {
+                    up.add_attr("disabled", "disabled");
+                }
+                let down = dom_element! {
+                    <button type="button" on_click={on_down}>{&params.move_down_label}</button>
+                };
+                if idx + 1 == count {
+                    down.add_attr("disabled", "disabled");
+                }
+
+                dom! {
+                    <div css={&entry_row_css}>
+                        <div css={&params.entry_css}>
+                            {..fields}
+                        </div>
+                        {up}
+                        {down}
+                        <button type="button" aria-label={&params.remove_label} on_click={on_remove}>
+                            <span aria-hidden="true">"×"</span>
+                        </button>
+                    </div>
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let on_add = bind!(group, |_| group.add());
+
+        dom! {
+            <div css={css! {"display: flex; flex-direction: column; gap: 5px;"}} role="group" {..attrs.clone()}>
+                {..rows}
+                <div>
+                    <button type="button" on_click={on_add}>{&params.add_label}</button>
+                </div>
+            </div>
+        }
+    })
+}
@@ -1,8 +1,57 @@
+use std::{collections::HashSet, rc::Rc};
+use vertigo::{AttrGroup, Computed, Css, Value};
+
+use crate::ValidationErrors;
+
+use super::FormExport;
+
 mod field;
 pub use field::Field;
 
 mod fields;
 pub(super) use fields::fields;
 
+mod group;
+
+mod nested;
+
 mod tabs;
-pub(super) use tabs::tabs;
+pub(super) use tabs::{first_tab_with_errors, is_error_in, tabs};
+
+mod wizard;
+pub(super) use wizard::wizard;
+
+/// State and styling shared by all sections of a rendered form.
+#[derive(Clone, Default)]
+pub(super) struct SectionsContext {
+    /// Attributes passed to every section
+    pub s: AttrGroup,
+    pub validation_errors: Value<ValidationErrors>,
+    pub section_css: Css,
+    pub dirty_section_css: Option<Css>,
+    /// Keys of fields that lost focus (tracked only if present)
+    pub blurred: Option<Value<HashSet<String>>>,
+    /// Path of rendered fields (i.e. `address.` in nested form or `lines.0.` in group entries),
+    /// prepended to keys of validation errors and blurred fields
+    pub prefix: String,
+    /// Current values of the form, used to evaluate conditions of sections
+    pub values: Option<Computed<FormExport>>,
+    /// Id of the form, prepended to ids of fields
+    pub id: String,
+    /// Text appended to labels of sections with required fields
+    pub required_marker: Option<Rc<String>>,
+    /// Show help of fields in a popup instead of inline text
+    pub help_popup: bool,
+}
+
+impl SectionsContext {
+    /// Id of a rendered field, derived from id of the form and path of the field.
+    pub fn field_id(&self, path: &str) -> String {
+        let path = path.replace('.', "-");
+        if self.id.is_empty() {
+            path
+        } else {
+            format!("{}-{path}", self.id)
+        }
+    }
+}
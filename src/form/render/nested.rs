@@ -0,0 +1,36 @@
+use vertigo::{AttrGroup, Computed, DomNode, css, dom};
+
+use super::{super::NestedValue, SectionsContext, fields::form_fields};
+
+/// Renders fields of a nested form, with keys prefixed by path of the field.
+///
+/// Attributes (i.e. `id` and `aria-*`) are set on the container of fields.
+pub(super) fn nested(
+    key: &str,
+    val: &NestedValue,
+    ctx: &SectionsContext,
+    attrs: AttrGroup,
+) -> DomNode {
+    let nested_ctx = SectionsContext {
+        prefix: format!("{}{key}.", ctx.prefix),
+        values: Some(Computed::from({
+            let form_data = val.form_data.clone();
+            move |ctx| form_data.export_all_in(ctx)
+        })),
+        ..ctx.clone()
+    };
+    let fields = form_fields(&val.form_data, &nested_ctx);
+    let css = val.css.clone().unwrap_or_else(|| {
+        css! {"
+            display: grid;
+            grid-template-columns: max-content 1fr;
+            gap: 5px;
+        "}
+    });
+
+    dom! {
+        <div {css} role="group" {..attrs}>
+            {..fields}
+        </div>
+    }
+}
@@ -1,24 +1,35 @@
 use std::rc::Rc;
-use vertigo::{AttrGroup, Css, DomNode, Value, dom};
+use vertigo::{Computed, Css, DomNode, Value, dom};
 
 use crate::{
     Tab, Tabs, TabsParams, ValidationErrors,
     form::{DataSection, fields},
 };
 
+use super::SectionsContext;
+
 pub(in super::super) fn tabs<'a>(
     tabs: &'a [(String, Rc<Vec<DataSection>>)],
     tabs_params: &'a Option<TabsParams>,
-    s: &'a AttrGroup,
-    validation_errors: Value<ValidationErrors>,
-    section_css: &'a Css,
+    ctx: &'a SectionsContext,
     form_css: &Css,
+    current_tab: &Value<String>,
+    badge_css: &Css,
 ) -> Option<DomNode> {
     if tabs.is_empty() {
         return None;
     }
 
-    let current_tab = Value::new(tabs[0].0.clone());
+    let mut params = tabs_params.clone().unwrap_or_default();
+    params.render_header_extra = Some({
+        let sections = tabs
+            .iter()
+            .map(|(_, sections)| sections.clone())
+            .collect::<Vec<_>>();
+        let errors = ctx.validation_errors.to_computed();
+        let badge_css = badge_css.clone();
+        Rc::new(move |idx| error_badge(&errors, &sections[idx], &badge_css))
+    });
 
     let tabs = tabs
         .iter()
@@ -27,12 +38,10 @@ pub(in super::super) fn tabs<'a>(
             name: label.to_string(),
             render: {
                 let sections = sections.clone();
-                let s = s.clone();
-                let validation_errors = validation_errors.clone();
-                let section_css = section_css.clone();
+                let ctx = ctx.clone();
                 let form_css = form_css.clone();
                 Rc::new(move |_| {
-                    let fields = fields(&sections, &s, validation_errors.clone(), &section_css);
+                    let fields = fields(&sections, &ctx);
                     dom! {
                         <div css={&form_css}>
                             {..fields}
@@ -43,13 +52,46 @@ pub(in super::super) fn tabs<'a>(
         })
         .collect::<Vec<_>>();
 
-    type FormTabs = Tabs<Value<String>, String>;
-
+    let current_tab = current_tab.clone();
     Some(dom! {
-        <FormTabs
-            tabs={tabs}
-            current_tab={current_tab}
-            params={tabs_params.clone().unwrap_or_default()}
-        />
+        <Tabs current_tab={&current_tab} {tabs} {params} />
     })
 }
+
+/// Count of errors of fields in provided sections, rendered if non-zero.
+pub(super) fn error_badge(
+    errors: &Computed<ValidationErrors>,
+    sections: &Rc<Vec<DataSection>>,
+    badge_css: &Css,
+) -> DomNode {
+    let sections = sections.clone();
+    let badge_css = badge_css.clone();
+    errors
+        .map(move |errors| {
+            errors
+                .keys()
+                .filter(|key| is_error_in(&sections, key))
+                .count()
+        })
+        .render_value_option(move |count| {
+            (count > 0).then(|| dom! { <span css={&badge_css}>{count}</span> })
+        })
+}
+
+/// Returns true if error key refers to a field in provided sections (or a field nested in it).
+pub(in super::super) fn is_error_in(sections: &[DataSection], key: &str) -> bool {
+    let root = key.split('.').next().unwrap_or_default();
+    sections
+        .iter()
+        .flat_map(|section| section.fields.iter())
+        .any(|field| field.key == root)
+}
+
+/// Index of the first tab containing a field with error.
+pub(in super::super) fn first_tab_with_errors(
+    tabs: &[(String, Rc<Vec<DataSection>>)],
+    errors: &ValidationErrors,
+) -> Option<usize> {
+    tabs.iter()
+        .position(|(_, sections)| errors.keys().any(|key| is_error_in(sections, key)))
+}
@@ -0,0 +1,73 @@
+use std::rc::Rc;
+use vertigo::{Css, DomNode, Value, dom};
+
+use crate::{
+    TabsParams,
+    form::{DataSection, fields},
+};
+
+use super::{SectionsContext, tabs::error_badge};
+
+/// Renders tabs as consecutive steps of a wizard, with progress indicator styled by [TabsParams].
+pub(in super::super) fn wizard(
+    tabs: &[(String, Rc<Vec<DataSection>>)],
+    tabs_params: &Option<TabsParams>,
+    ctx: &SectionsContext,
+    form_css: &Css,
+    step: &Value<usize>,
+    badge_css: &Css,
+) -> Option<DomNode> {
+    if tabs.is_empty() {
+        return None;
+    }
+
+    let params = tabs_params.clone().unwrap_or_default();
+
+    let progress = {
+        let tabs = tabs.to_vec();
+        let params = params.clone();
+        let errors = ctx.validation_errors.to_computed();
+        let badge_css = badge_css.clone();
+        step.render_value(move |current| {
+            let items = tabs.iter().enumerate().map(|(idx, (label, sections))| {
+                let mut css = &params.header_item_css + &params.header_item_add_css;
+                if idx == current {
+                    css += &params.header_active_item_add_css;
+                }
+                let badge = error_badge(&errors, sections, &badge_css);
+                dom! { <li {css}>{format!("{}. {label}", idx + 1)} {badge}</li> }
+            });
+            dom! {
+                <ol css={&params.header_css}>
+                    {..items}
+                </ol>
+            }
+        })
+    };
+
+    let content = {
+        let tabs = tabs.to_vec();
+        let ctx = ctx.clone();
+        let form_css = form_css.clone();
+        step.render_value(move |current| {
+            let fields = tabs
+                .get(current)
+                .into_iter()
+                .flat_map(|(_, sections)| fields(sections, &ctx));
+            dom! {
+                <div css={&form_css}>
+                    {..fields}
+                </div>
+            }
+        })
+    };
+
+    Some(dom! {
+        <div css={params.container_css}>
+            {progress}
+            <div css={params.content_css}>
+                {content}
+            </div>
+        </div>
+    })
+}
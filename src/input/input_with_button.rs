@@ -1,5 +1,7 @@
 use vertigo::{AttrGroup, Value, bind, component, computed_tuple, dom, transaction};
 
+use crate::locale;
+
 /// Input connected to provided `Value<String>`.
 ///
 /// ```
@@ -69,7 +71,7 @@ pub struct InputWithButtonParams {
 impl Default for InputWithButtonParams {
     fn default() -> Self {
         Self {
-            button_label: "OK".to_string(),
+            button_label: locale().ok.clone(),
         }
     }
 }
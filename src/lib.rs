@@ -1,12 +1,17 @@
 use std::{collections::HashMap, fmt::Display, str::FromStr};
 
 pub mod button;
+pub mod date;
+mod date_picker;
+mod deferred;
 mod drop_image_file;
 pub mod form;
 mod image_upload;
 mod input;
+mod locale;
 pub mod login;
 mod popup;
+mod radio_group;
 pub mod resource_table;
 mod search_panel;
 mod select;
@@ -14,20 +19,27 @@ mod select_search;
 mod spinner;
 mod switch;
 mod tabs;
+mod theme;
 mod with_loader;
 
+use deferred::defer;
+
 pub use {
     button::{Button, ButtonColor, ButtonVariant, TableButton},
+    date_picker::{Calendar, CalendarParams, DatePicker, DatePickerParams, DateTimePicker, today},
     drop_image_file::{DropImageFile, DropImageFileParams, image_as_uri, name_to_mime},
     image_upload::ImageUpload,
     input::{Input, InputWithButton, InputWithButtonParams, ListInput},
+    locale::{Locale, Plural, PluralRule, locale, set_locale},
     popup::{Popup, PopupOnHover, PopupParams},
+    radio_group::{DictRadioGroup, RadioGroup, RadioGroupParams, RadioLayout},
     search_panel::{SearchPanel, SearchPanelParams, SearchResult},
     select::{DictSelect, MultiDropDown, MultiDropDownParams, MultiSelect, Select},
     select_search::{SelectSearch, SelectSearchParams},
     spinner::Spinner,
     switch::{Switch, SwitchParams},
     tabs::{Tab, Tabs, TabsContent, TabsContentMapped, TabsHeader, TabsParams},
+    theme::Theme,
     with_loader::{WithLoader, with_loader},
 };
 
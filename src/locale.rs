@@ -0,0 +1,416 @@
+use std::{cell::RefCell, rc::Rc};
+
+thread_local! {
+    static LOCALE: RefCell<Rc<Locale>> = RefCell::new(Rc::new(Locale::default()));
+}
+
+/// Returns texts currently used by components (see [set_locale]).
+pub fn locale() -> Rc<Locale> {
+    LOCALE.with(|locale| locale.borrow().clone())
+}
+
+/// Set texts used by components.
+///
+/// Params of components (like [FormParams](crate::form::FormParams) or [DateFormat](crate::date::DateFormat))
+/// read the locale when created by `Default`, so it should be set once, before the application is rendered.
+/// Labels can still be overridden in params of particular component.
+///
+/// ```rust
+/// use vertigo_forms::{
+///     Locale, SearchPanelParams, set_locale,
+///     date::DateFormat,
+///     form::{FieldExport, FormParams, Validator},
+/// };
+///
+/// set_locale(Locale::polish());
+///
+/// assert_eq!(FormParams::<()>::default().submit_label.as_str(), "Wyślij");
+/// assert_eq!(SearchPanelParams::default().empty_text, "Brak wyników");
+/// assert_eq!(DateFormat::european().month_names[0], "Styczeń");
+/// assert_eq!(
+///     Validator::min_len(3).validate(&FieldExport::String("ab".to_string())).unwrap(),
+///     "Wymagane co najmniej 3 znaki",
+/// );
+///
+/// set_locale(Locale {
+///     submit: "Send".to_string(),
+///     ..Locale::english()
+/// });
+/// assert_eq!(FormParams::<()>::default().submit_label.as_str(), "Send");
+/// ```
+pub fn set_locale(locale: Locale) {
+    LOCALE.with(|current| current.replace(Rc::new(locale)));
+}
+
+/// Texts used by components, in one place.
+///
+/// Texts with placeholders in braces (i.e. `{min}`) get them replaced with actual values.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Locale {
+    // Form
+    pub submit: String,
+    pub delete: String,
+    pub saving: String,
+    pub saved: String,
+    pub next: String,
+    pub back: String,
+    pub draft_found: String,
+    pub draft_restore: String,
+    pub draft_discard: String,
+    /// Shown while asynchronous validation is pending
+    pub checking: String,
+    /// Adds an entry to group or multi field
+    pub add: String,
+    /// Accessible name of a button removing an entry from group or multi field (it shows "×")
+    pub remove: String,
+
+    /// Selects forms of [Plural] texts
+    pub plural_rule: PluralRule,
+
+    // Validators
+    pub required: String,
+    /// `{min}` - minimal length
+    pub min_len: Plural,
+    /// `{max}` - maximal length
+    pub max_len: Plural,
+    pub invalid_format: String,
+    pub not_integer: String,
+    pub not_number: String,
+    /// `{min}` - minimal value
+    pub min_value: String,
+    /// `{max}` - maximal value
+    pub max_value: String,
+    /// `{min}`, `{max}` - bounds of allowed range
+    pub between: String,
+    /// `{min}` - earliest date or time
+    pub min_date: String,
+    /// `{max}` - latest date or time
+    pub max_date: String,
+
+    // Dates
+    /// Names of months, starting from January
+    pub month_names: Vec<String>,
+    /// Short names of days of week, starting from Monday
+    pub weekday_names: Vec<String>,
+
+    // Other components
+    pub loading: String,
+    pub search_prompt: String,
+    /// `{min_chars}` - minimal length of query
+    pub search_hint: String,
+    pub no_results: String,
+    pub enter_phrase: String,
+    pub revert: String,
+    pub cancel: String,
+    pub no_image: String,
+    pub select_file: String,
+    pub switch_on: String,
+    pub switch_off: String,
+    pub ok: String,
+    pub non_existent_tab: String,
+    pub edit: String,
+    pub save: String,
+    pub confirm_delete: String,
+    pub confirm_delete_question: String,
+    pub processing: String,
+    pub username: String,
+    pub password: String,
+    pub login: String,
+    pub logging_in: String,
+}
+
+impl Default for Locale {
+    fn default() -> Self {
+        Self::english()
+    }
+}
+
+impl Locale {
+    pub fn english() -> Self {
+        Self {
+            submit: "Submit".to_string(),
+            delete: "Delete".to_string(),
+            saving: "Saving...".to_string(),
+            saved: "Saved".to_string(),
+            next: "Next".to_string(),
+            back: "Back".to_string(),
+            draft_found: "You have unsaved changes.".to_string(),
+            draft_restore: "Restore".to_string(),
+            draft_discard: "Discard".to_string(),
+            checking: "Checking...".to_string(),
+            add: "Add".to_string(),
+            remove: "Remove".to_string(),
+            plural_rule: PluralRule::OneOther,
+
+            required: "This field is required".to_string(),
+            min_len: plural(
+                "Must be at least {min} character long",
+                "Must be at least {min} characters long",
+                "Must be at least {min} characters long",
+            ),
+            max_len: plural(
+                "Must be at most {max} character long",
+                "Must be at most {max} characters long",
+                "Must be at most {max} characters long",
+            ),
+            invalid_format: "Invalid format".to_string(),
+            not_integer: "Must be an integer".to_string(),
+            not_number: "Must be a number".to_string(),
+            min_value: "Must be at least {min}".to_string(),
+            max_value: "Must be at most {max}".to_string(),
+            between: "Must be between {min} and {max}".to_string(),
+            min_date: "Must be {min} or later".to_string(),
+            max_date: "Must be {max} or earlier".to_string(),
+
+            month_names: strings(&[
+                "January",
+                "February",
+                "March",
+                "April",
+                "May",
+                "June",
+                "July",
+                "August",
+                "September",
+                "October",
+                "November",
+                "December",
+            ]),
+            weekday_names: strings(&["Mo", "Tu", "We", "Th", "Fr", "Sa", "Su"]),
+
+            loading: "Loading...".to_string(),
+            search_prompt: "Search: ".to_string(),
+            search_hint: "Enter at least {min_chars} letters".to_string(),
+            no_results: "No results".to_string(),
+            enter_phrase: "Enter phrase".to_string(),
+            revert: "Revert".to_string(),
+            cancel: "Cancel".to_string(),
+            no_image: "No image".to_string(),
+            select_file: "Select file...".to_string(),
+            switch_on: "ON".to_string(),
+            switch_off: "OFF".to_string(),
+            ok: "OK".to_string(),
+            non_existent_tab: "Non-existent tab set".to_string(),
+            edit: "Edit".to_string(),
+            save: "Save".to_string(),
+            confirm_delete: "Confirm delete".to_string(),
+            confirm_delete_question: "Are you sure you want to delete this record?".to_string(),
+            processing: "Processing...".to_string(),
+            username: "Username:".to_string(),
+            password: "Password:".to_string(),
+            login: "Login".to_string(),
+            logging_in: "Logging in...".to_string(),
+        }
+    }
+
+    pub fn polish() -> Self {
+        Self {
+            submit: "Wyślij".to_string(),
+            delete: "Usuń".to_string(),
+            saving: "Zapisywanie...".to_string(),
+            saved: "Zapisano".to_string(),
+            next: "Dalej".to_string(),
+            back: "Wstecz".to_string(),
+            draft_found: "Masz niezapisane zmiany.".to_string(),
+            draft_restore: "Przywróć".to_string(),
+            draft_discard: "Odrzuć".to_string(),
+            checking: "Sprawdzanie...".to_string(),
+            add: "Dodaj".to_string(),
+            remove: "Usuń".to_string(),
+            plural_rule: PluralRule::OneFewMany,
+
+            required: "To pole jest wymagane".to_string(),
+            min_len: plural(
+                "Wymagany co najmniej {min} znak",
+                "Wymagane co najmniej {min} znaki",
+                "Wymaganych co najmniej {min} znaków",
+            ),
+            max_len: plural(
+                "Dozwolony najwyżej {max} znak",
+                "Dozwolone najwyżej {max} znaki",
+                "Dozwolonych najwyżej {max} znaków",
+            ),
+            invalid_format: "Nieprawidłowy format".to_string(),
+            not_integer: "Wymagana liczba całkowita".to_string(),
+            not_number: "Wymagana liczba".to_string(),
+            min_value: "Wymagana wartość co najmniej {min}".to_string(),
+            max_value: "Dozwolona wartość najwyżej {max}".to_string(),
+            between: "Wymagana wartość od {min} do {max}".to_string(),
+            min_date: "Nie wcześniej niż {min}".to_string(),
+            max_date: "Nie później niż {max}".to_string(),
+
+            month_names: strings(&[
+                "Styczeń",
+                "Luty",
+                "Marzec",
+                "Kwiecień",
+                "Maj",
+                "Czerwiec",
+                "Lipiec",
+                "Sierpień",
+                "Wrzesień",
+                "Październik",
+                "Listopad",
+                "Grudzień",
+            ]),
+            weekday_names: strings(&["Pn", "Wt", "Śr", "Cz", "Pt", "So", "Nd"]),
+
+            loading: "Ładowanie...".to_string(),
+            search_prompt: "Szukaj: ".to_string(),
+            search_hint: "Wpisz co najmniej {min_chars} litery".to_string(),
+            no_results: "Brak wyników".to_string(),
+            enter_phrase: "Wpisz frazę".to_string(),
+            revert: "Przywróć".to_string(),
+            cancel: "Anuluj".to_string(),
+            no_image: "Brak obrazu".to_string(),
+            select_file: "Wybierz plik...".to_string(),
+            switch_on: "WŁ".to_string(),
+            switch_off: "WYŁ".to_string(),
+            ok: "OK".to_string(),
+            non_existent_tab: "Nieistniejąca zakładka".to_string(),
+            edit: "Edytuj".to_string(),
+            save: "Zapisz".to_string(),
+            confirm_delete: "Potwierdź usunięcie".to_string(),
+            confirm_delete_question: "Czy na pewno chcesz usunąć ten rekord?".to_string(),
+            processing: "Przetwarzanie...".to_string(),
+            username: "Nazwa użytkownika:".to_string(),
+            password: "Hasło:".to_string(),
+            login: "Zaloguj".to_string(),
+            logging_in: "Logowanie...".to_string(),
+        }
+    }
+
+    pub fn german() -> Self {
+        Self {
+            submit: "Absenden".to_string(),
+            delete: "Löschen".to_string(),
+            saving: "Wird gespeichert...".to_string(),
+            saved: "Gespeichert".to_string(),
+            next: "Weiter".to_string(),
+            back: "Zurück".to_string(),
+            draft_found: "Sie haben ungespeicherte Änderungen.".to_string(),
+            draft_restore: "Wiederherstellen".to_string(),
+            draft_discard: "Verwerfen".to_string(),
+            checking: "Wird geprüft...".to_string(),
+            add: "Hinzufügen".to_string(),
+            remove: "Entfernen".to_string(),
+            plural_rule: PluralRule::OneOther,
+
+            required: "Dieses Feld ist erforderlich".to_string(),
+            min_len: plural(
+                "Mindestens {min} Zeichen erforderlich",
+                "Mindestens {min} Zeichen erforderlich",
+                "Mindestens {min} Zeichen erforderlich",
+            ),
+            max_len: plural(
+                "Höchstens {max} Zeichen erlaubt",
+                "Höchstens {max} Zeichen erlaubt",
+                "Höchstens {max} Zeichen erlaubt",
+            ),
+            invalid_format: "Ungültiges Format".to_string(),
+            not_integer: "Muss eine ganze Zahl sein".to_string(),
+            not_number: "Muss eine Zahl sein".to_string(),
+            min_value: "Muss mindestens {min} sein".to_string(),
+            max_value: "Darf höchstens {max} sein".to_string(),
+            between: "Muss zwischen {min} und {max} liegen".to_string(),
+            min_date: "Frühestens {min}".to_string(),
+            max_date: "Spätestens {max}".to_string(),
+
+            month_names: strings(&[
+                "Januar",
+                "Februar",
+                "März",
+                "April",
+                "Mai",
+                "Juni",
+                "Juli",
+                "August",
+                "September",
+                "Oktober",
+                "November",
+                "Dezember",
+            ]),
+            weekday_names: strings(&["Mo", "Di", "Mi", "Do", "Fr", "Sa", "So"]),
+
+            loading: "Wird geladen...".to_string(),
+            search_prompt: "Suche: ".to_string(),
+            search_hint: "Mindestens {min_chars} Buchstaben eingeben".to_string(),
+            no_results: "Keine Ergebnisse".to_string(),
+            enter_phrase: "Suchbegriff eingeben".to_string(),
+            revert: "Zurücksetzen".to_string(),
+            cancel: "Abbrechen".to_string(),
+            no_image: "Kein Bild".to_string(),
+            select_file: "Datei auswählen...".to_string(),
+            switch_on: "EIN".to_string(),
+            switch_off: "AUS".to_string(),
+            ok: "OK".to_string(),
+            non_existent_tab: "Nicht vorhandener Tab".to_string(),
+            edit: "Bearbeiten".to_string(),
+            save: "Speichern".to_string(),
+            confirm_delete: "Löschen bestätigen".to_string(),
+            confirm_delete_question: "Möchten Sie diesen Datensatz wirklich löschen?".to_string(),
+            processing: "Wird verarbeitet...".to_string(),
+            username: "Benutzername:".to_string(),
+            password: "Passwort:".to_string(),
+            login: "Anmelden".to_string(),
+            logging_in: "Anmeldung läuft...".to_string(),
+        }
+    }
+}
+
+/// Text with forms depending on a number (see [PluralRule]).
+///
+/// ```rust
+/// use vertigo_forms::Locale;
+///
+/// let locale = Locale::polish();
+/// let text = |n: usize| locale.min_len.get(locale.plural_rule, n).replace("{min}", &n.to_string());
+/// assert_eq!(text(1), "Wymagany co najmniej 1 znak");
+/// assert_eq!(text(3), "Wymagane co najmniej 3 znaki");
+/// assert_eq!(text(5), "Wymaganych co najmniej 5 znaków");
+/// assert_eq!(text(12), "Wymaganych co najmniej 12 znaków");
+/// assert_eq!(text(22), "Wymagane co najmniej 22 znaki");
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Plural {
+    /// For 1
+    pub one: String,
+    /// For numbers ending with 2-4 in [PluralRule::OneFewMany]
+    pub few: String,
+    /// For other numbers
+    pub many: String,
+}
+
+impl Plural {
+    /// Form of the text for provided number.
+    pub fn get(&self, rule: PluralRule, number: usize) -> &str {
+        let few = matches!(number % 10, 2..=4) && !matches!(number % 100, 12..=14);
+        match rule {
+            _ if number == 1 => &self.one,
+            PluralRule::OneFewMany if few => &self.few,
+            _ => &self.many,
+        }
+    }
+}
+
+/// How the number selects the form of a [Plural] text.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum PluralRule {
+    /// Singular for 1, plural otherwise (i.e. English, German)
+    #[default]
+    OneOther,
+    /// Singular for 1, "few" form for numbers ending with 2-4 (except 12-14), "many" otherwise (i.e. Polish)
+    OneFewMany,
+}
+
+fn plural(one: &str, few: &str, many: &str) -> Plural {
+    Plural {
+        one: one.to_string(),
+        few: few.to_string(),
+        many: many.to_string(),
+    }
+}
+
+fn strings(items: &[&str]) -> Vec<String> {
+    items.iter().map(|item| item.to_string()).collect()
+}
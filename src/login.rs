@@ -4,6 +4,8 @@ use vertigo::{
     transaction,
 };
 
+use crate::{locale, theme::var};
+
 pub type OnSubmit = Rc<dyn Fn(&str, &str)>;
 
 /// Custom renderer for a login input. Receives the value bound to the form so
@@ -56,12 +58,14 @@ pub struct LoginParams {
 
 impl Default for LoginParams {
     fn default() -> Self {
+        let locale = locale();
         Self {
             css: css! {"
                 width: 250px;
                 margin: auto;
                 padding: 10px;
                 margin-bottom: 10px;
+                font-family: {var::FONT_FAMILY};
             "},
             add_css: Css::default(),
             line_css: css! {"
@@ -75,10 +79,10 @@ impl Default for LoginParams {
             "},
             submit_add_css: Css::default(),
             error_message: Rc::new(|err| err),
-            username_label: "Username:".to_string(),
-            password_label: "Password:".to_string(),
-            button_label: "Login".to_string(),
-            waiting_label: "Logging in...".to_string(),
+            username_label: locale.username.clone(),
+            password_label: locale.password.clone(),
+            button_label: locale.login.clone(),
+            waiting_label: locale.logging_in.clone(),
             header: None,
             footer: None,
             render_username: None,
@@ -119,7 +123,7 @@ pub fn mount_login<T: Clone + PartialEq + 'static>(
     let message_div = bind!(
         line_css,
         token_result.render_value(move |token_result| {
-            let css_error = line_css.clone().push_str("color: red;");
+            let css_error = line_css.clone() + css! {"color: {var::DANGER};"};
 
             match token_result {
                 Some(Resource::Loading) => dom! {
@@ -0,0 +1,221 @@
+use std::rc::Rc;
+use vertigo::{AttrGroup, Computed, Css, DomNode, Value, component, computed_tuple, css, dom};
+
+use crate::theme::var;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum RadioLayout {
+    /// One option per line
+    #[default]
+    Vertical,
+    /// Options in a row
+    Horizontal,
+    /// Options rendered as joined buttons, with radio inputs hidden
+    Segmented,
+}
+
+#[derive(Clone)]
+pub struct RadioGroupParams {
+    pub layout: RadioLayout,
+    /// Css for every option (label wrapping radio input)
+    pub item_css: Css,
+    /// Css added to selected option in segmented layout
+    pub segment_active_css: Css,
+}
+
+impl Default for RadioGroupParams {
+    fn default() -> Self {
+        Self {
+            layout: Default::default(),
+            item_css: Css::default(),
+            segment_active_css: css! {"
+                background: {var::PRIMARY};
+                color: {var::ON_ACCENT};
+            "},
+        }
+    }
+}
+
+impl RadioGroupParams {
+    pub fn horizontal() -> Self {
+        Self {
+            layout: RadioLayout::Horizontal,
+            ..Default::default()
+        }
+    }
+
+    pub fn segmented() -> Self {
+        Self {
+            layout: RadioLayout::Segmented,
+            ..Default::default()
+        }
+    }
+}
+
+/// Radio buttons for a list of options, alternative for [Select](crate::Select).
+///
+/// Use `i` attribute group to pass anything to every radio input (ex. `i:name="group"`),
+/// and `g` attribute group to pass anything to the container (ex. `g:role="radiogroup"`).
+///
+/// Example:
+/// ```
+/// use vertigo::{Value, dom};
+/// use vertigo_forms::{RadioGroup, RadioGroupParams};
+///
+/// let value = Value::new("tea".to_string());
+/// let options = Value::new(vec!["tea".to_string(), "coffee".to_string()]);
+///
+/// dom! {
+///     <RadioGroup
+///         value={value.clone()}
+///         options={options}
+///         params={RadioGroupParams::segmented()}
+///     />
+/// };
+/// ```
+#[component]
+pub fn RadioGroup<T: Clone + PartialEq + ToString + 'static>(
+    value: Value<T>,
+    options: Computed<Vec<T>>,
+    params: RadioGroupParams,
+    i: AttrGroup,
+    g: AttrGroup,
+) {
+    let value_clone = value.clone();
+    computed_tuple!(value, options).render_value(move |(value, options)| {
+        let items = options
+            .into_iter()
+            .map(|option| {
+                let checked = option == value;
+                let label = option.to_string();
+                let select = {
+                    let value = value_clone.clone();
+                    Rc::new(move || value.set(option.clone())) as Rc<dyn Fn()>
+                };
+                RadioItem {
+                    label,
+                    checked,
+                    select,
+                }
+            })
+            .collect();
+        render_options(items, &params, &i, &g)
+    })
+}
+
+/// Radio buttons based on map of `i64`->`T` values, alternative for [DictSelect](crate::DictSelect).
+///
+/// Use `i` attribute group to pass anything to every radio input (ex. `i:name="group"`),
+/// and `g` attribute group to pass anything to the container.
+#[component]
+pub fn DictRadioGroup<T: Clone + PartialEq + ToString + 'static>(
+    value: Value<i64>,
+    options: Computed<Vec<(i64, T)>>,
+    params: RadioGroupParams,
+    i: AttrGroup,
+    g: AttrGroup,
+) {
+    let value_clone = value.clone();
+    computed_tuple!(value, options).render_value(move |(value, options)| {
+        let items = options
+            .into_iter()
+            .map(|(key, item)| {
+                let select = {
+                    let value = value_clone.clone();
+                    Rc::new(move || value.set(key)) as Rc<dyn Fn()>
+                };
+                RadioItem {
+                    label: item.to_string(),
+                    checked: key == value,
+                    select,
+                }
+            })
+            .collect();
+        render_options(items, &params, &i, &g)
+    })
+}
+
+struct RadioItem {
+    label: String,
+    checked: bool,
+    select: Rc<dyn Fn()>,
+}
+
+fn render_options(
+    items: Vec<RadioItem>,
+    params: &RadioGroupParams,
+    i: &AttrGroup,
+    g: &AttrGroup,
+) -> DomNode {
+    let (container_css, item_css, input_css) = match params.layout {
+        RadioLayout::Vertical => (
+            css! {"display: flex; flex-direction: column; gap: 2px;"},
+            css! {"cursor: pointer;"},
+            Css::default(),
+        ),
+        RadioLayout::Horizontal => (
+            css! {"display: flex; flex-wrap: wrap; gap: 10px;"},
+            css! {"cursor: pointer;"},
+            Css::default(),
+        ),
+        RadioLayout::Segmented => (
+            css! {"
+                display: inline-flex;
+                border: 1px solid;
+                border-color: {var::PRIMARY};
+                border-radius: {var::RADIUS_SM};
+                overflow: hidden;
+            "},
+            css! {"
+                cursor: pointer;
+                padding: {var::SPACING_SM} 12px;
+                color: {var::PRIMARY};
+                border-right: 1px solid;
+                border-right-color: {var::PRIMARY};
+                :last-child { border-right: none; }
+            "},
+            css! {"
+                position: absolute;
+                opacity: 0;
+                pointer-events: none;
+            "},
+        ),
+    };
+
+    let items = items.into_iter().map(
+        |RadioItem {
+             label,
+             checked,
+             select,
+         }| {
+            let mut css = &item_css + &params.item_css;
+            if checked && params.layout == RadioLayout::Segmented {
+                css += &params.segment_active_css;
+            }
+            let on_change = move |_| select();
+            let i = i.clone();
+            if checked {
+                dom! {
+                    <label {css}>
+                        <input type="radio" css={&input_css} checked="checked" {on_change} {..i} />
+                        {label}
+                    </label>
+                }
+            } else {
+                dom! {
+                    <label {css}>
+                        <input type="radio" css={&input_css} {on_change} {..i} />
+                        {label}
+                    </label>
+                }
+            }
+        },
+    );
+
+    let g = g.clone();
+    dom! {
+        <div css={container_css} {..g}>
+            {..items}
+        </div>
+    }
+}
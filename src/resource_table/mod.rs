@@ -1,7 +1,7 @@
 use std::rc::Rc;
 use vertigo::{Computed, Css, DomNode, Resource, Value, bind_rc, css, dom};
 
-use crate::button::TableButton;
+use crate::{button::TableButton, locale, theme::var};
 
 pub mod row_from_data_section;
 pub use row_from_data_section::row_from_data_section;
@@ -103,7 +103,7 @@ impl<Model: Clone + PartialEq + Default + 'static, ModelForm: Clone + 'static>
                     }
                 }
                 Resource::Loading => {
-                    dom! { <div>"Loading..."</div> }
+                    dom! { <div>{&locale().loading}</div> }
                 }
                 Resource::Error(err) => {
                     dom! { <div>{err}</div> }
@@ -116,12 +116,14 @@ impl<Model: Clone + PartialEq + Default + 'static, ModelForm: Clone + 'static>
         let table_css = css! {"
             width: 100%;
             max-width: 1600px;
-            background: #ffffff;
-            border: 1px solid #e0e0e0;
+            background: {var::BACKGROUND};
+            border: 1px solid;
+            border-color: {var::BORDER_LIGHT};
             border-radius: 12px;
             box-shadow: 0 4px 12px rgba(0, 0, 0, 0.05);
             overflow: hidden;
             margin: 0 auto;
+            font-family: {var::FONT_FAMILY};
         "} + &props.table_css;
 
         dom! {
@@ -135,10 +137,11 @@ impl<Model: Clone + PartialEq + Default + 'static, ModelForm: Clone + 'static>
                             justify-content: space-between;
                             align-items: center;
                             padding: 20px;
-                            border-bottom: 1px solid #eee;
+                            border-bottom: 1px solid;
+                            border-bottom-color: {var::BORDER_LIGHT};
                             gap: 10px;
                         "}}>
-                        <h2 css={css! {"margin: 0; font-size: 1.2rem; color: #333;"}}>{props.title.clone()}</h2>
+                        <h2 css={css! {"margin: 0; font-size: 1.2rem; color: {var::TEXT};"}}>{props.title.clone()}</h2>
 
                         {..props.render_filters.map(|render_filters| render_filters())}
 
@@ -222,14 +225,15 @@ fn render_add<Model: Clone + PartialEq + Default + 'static, ModelForm: Clone + '
 pub fn base_header_css() -> Css {
     css! {"
         display: grid;
-        background: #f8f9fa;
+        background: {var::SURFACE};
         padding: 12px 20px;
         align-items: center;
 
         font-size: 0.85rem;
         font-weight: 700;
-        color: #666;
-        border-bottom: 1px solid #eee;
+        color: {var::TEXT_MUTED};
+        border-bottom: 1px solid;
+        border-bottom-color: {var::BORDER_LIGHT};
     "}
 }
 
@@ -241,36 +245,37 @@ pub fn base_row_css() -> Css {
         gap: 5px;
 
         transition: background 0.2s;
-        :hover { background: #f8f8f8; }
+        :hover { background: {var::SURFACE}; }
     "}
 }
 
 pub fn edit_input_css() -> Css {
     css! {"
-        width: 90%; padding: 4px 8px; border: 1px solid #ccc; border-radius: 4px;
+        width: 90%; padding: {var::SPACING_SM} {var::SPACING_MD}; border: 1px solid; border-color: {var::BORDER};
+        border-radius: {var::RADIUS_SM};
     "}
 }
 
 pub fn read_only_col_css() -> Css {
     css! {"
-        font-family: monospace; color: #888;
+        font-family: monospace; color: {var::TEXT_MUTED};
     "}
 }
 
 pub fn main_col_css() -> Css {
     css! {"
-        font-weight: 500; color: #333;
+        font-weight: 500; color: {var::TEXT};
     "}
 }
 
 pub fn normal_col_css() -> Css {
     css! {"
-        color: #333; font-size: 0.9rem;
+        color: {var::TEXT}; font-size: {var::FONT_SIZE};
     "}
 }
 
 pub fn low_prio_col_css() -> Css {
     css! {"
-        color: #666; font-size: 0.9rem;
+        color: {var::TEXT_MUTED}; font-size: {var::FONT_SIZE};
     "}
 }
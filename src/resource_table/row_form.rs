@@ -1,7 +1,11 @@
 use std::{future::Future, pin::Pin, rc::Rc};
 use vertigo::{Computed, DomComment, DomNode, Value, bind_rc, bind_spawn, css, dom, transaction};
 
-use crate::button::{Button, ButtonColor, ButtonVariant};
+use crate::{
+    button::{Button, ButtonColor, ButtonVariant},
+    locale,
+    theme::var,
+};
 
 pub type AsyncResult<R> = Pin<Box<dyn Future<Output = R>>>;
 pub type ProcessCallback<Model> = Rc<dyn Fn(Model) -> AsyncResult<Option<String>>>;
@@ -29,14 +33,15 @@ pub struct ResourceTableLabels {
 
 impl Default for ResourceTableLabels {
     fn default() -> Self {
+        let locale = locale();
         Self {
-            edit: "Edit".to_string(),
-            delete: "Delete".to_string(),
-            confirm_delete: "Confirm delete".to_string(),
-            cancel: "Cancel".to_string(),
-            confirm_question: "Are you sure you want to delete this record?".to_string(),
-            processing: "Processing...".to_string(),
-            save: "Save".to_string(),
+            edit: locale.edit.clone(),
+            delete: locale.delete.clone(),
+            confirm_delete: locale.confirm_delete.clone(),
+            cancel: locale.cancel.clone(),
+            confirm_question: locale.confirm_delete_question.clone(),
+            processing: locale.processing.clone(),
+            save: locale.save.clone(),
         }
     }
 }
@@ -79,7 +84,7 @@ pub fn row_form<Model: Clone + PartialEq + 'static, FormModel: Clone + 'static>(
     state.clone().render_value(move |current_state| {
         let error_view = error.clone().render_value(|err| {
             if let Some(err) = err {
-                dom! { <div css={css! {"color: #dc3545; font-size: 0.8rem; margin: 8px 20px; font-weight: 600;"}}>{err}</div> }
+                dom! { <div css={css! {"color: {var::DANGER}; font-size: {var::FONT_SIZE_SM}; margin: 8px 20px; font-weight: 600;"}}>{err}</div> }
             } else {
                 dom! { <div /> }
             }
@@ -228,9 +233,10 @@ pub fn row_form<Model: Clone + PartialEq + 'static, FormModel: Clone + 'static>(
                             <div css={css! {"
                                 padding: 24px 20px;
                                 text-align: center;
-                                color: #888;
-                                background: #f9f9f9;
-                                border-bottom: 1px solid #eee;
+                                color: {var::TEXT_MUTED};
+                                background: {var::SURFACE};
+                                border-bottom: 1px solid;
+                                border-bottom-color: {var::BORDER_LIGHT};
                             "}}>
                                 <span css={css! {"
                                     display: inline-block;
@@ -258,9 +264,10 @@ fn confirm_delete_view(
 
     dom! {
         <div css={css! {"
-            background: #fff0f0;
+            background: {var::DANGER_BACKGROUND};
             padding: 16px 20px;
-            border-bottom: 1px solid #ffcccc;
+            border-bottom: 1px solid;
+            border-bottom-color: {var::DANGER_BORDER};
 
             display: flex;
             flex-direction: row;
@@ -268,7 +275,7 @@ fn confirm_delete_view(
         "}}>
             <div css={css! {"
                 font-weight: 600;
-                color: #b02a37;
+                color: {var::DANGER_TEXT};
 
                 display: flex;
                 align-items: center;
@@ -3,6 +3,7 @@ use vertigo::{DomNode, css, dom};
 use crate::{
     form::{DataSection, Field},
     resource_table::{base_row_css, normal_col_css},
+    theme::var,
 };
 
 /// Renders a single row in a `ResourceTable` using a `DataSection`.
@@ -38,7 +39,7 @@ pub fn row_from_data_section(
         .collect();
 
     let error_node = section.error.as_ref().map(|err| {
-        dom! { <span css={css! {"color: red; font-size: 0.8rem;"}}>{err}</span> }
+        dom! { <span css={css! {"color: {var::DANGER}; font-size: {var::FONT_SIZE_SM};"}}>{err}</span> }
     });
 
     dom! {
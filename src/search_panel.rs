@@ -1,6 +1,8 @@
 use std::rc::Rc;
 use vertigo::{AttrGroup, AutoMap, DomNode, Resource, ToComputed, Value, bind, component, dom};
 
+use crate::locale;
+
 pub trait SearchResult {
     fn is_empty(&self) -> bool;
 }
@@ -22,12 +24,13 @@ pub struct SearchPanelParams {
 
 impl Default for SearchPanelParams {
     fn default() -> Self {
+        let locale = locale();
         Self {
             min_chars: 3,
-            prompt: "Search: ".to_string(),
-            hint: "Enter at least {min_chars} letters".to_string(),
-            loading_text: "Loading...".to_string(),
-            empty_text: "No results".to_string(),
+            prompt: locale.search_prompt.clone(),
+            hint: locale.search_hint.clone(),
+            loading_text: locale.loading.clone(),
+            empty_text: locale.no_results.clone(),
         }
     }
 }
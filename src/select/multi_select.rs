@@ -1,5 +1,7 @@
 use vertigo::{Computed, Css, DomNode, Value, bind, bind_rc, css, dom, render::render_list};
 
+use crate::theme::var;
+
 /// Select component based on vector of `T` values,
 /// which allows to have multiple options selected at once.
 ///
@@ -65,7 +67,7 @@ where
                                 css! {"
                             border-style: inset;
                             font-weight: bold;
-                            color: green;
+                            color: {var::SUCCESS};
                         "}
                             } else {
                                 Css::default()
@@ -1,6 +1,8 @@
 use std::ops::Not;
 
-use vertigo::{Computed, DomNode, Value, bind, computed_tuple, dom, render::render_list};
+use vertigo::{
+    AttrGroup, Computed, DomNode, Value, bind, computed_tuple, dom, render::render_list,
+};
 
 /// Simple Select component based on vector of `T` values.
 ///
@@ -39,39 +41,50 @@ where
     }
 
     pub fn mount(&self) -> DomNode {
-        let Self { value, options } = self;
-        let on_change = bind!(value, |new_value: String| {
-            value.set(new_value.into());
-        });
+        render_select(&self.value, &self.options, AttrGroup::default())
+    }
+}
+
+/// Renders [Select] passing `attrs` to the select element.
+pub(crate) fn render_select<T>(
+    value: &Value<T>,
+    options: &Computed<Vec<T>>,
+    attrs: AttrGroup,
+) -> DomNode
+where
+    T: Clone + From<String> + PartialEq + ToString + 'static,
+{
+    let on_change = bind!(value, |new_value: String| {
+        value.set(new_value.into());
+    });
 
-        let empty = computed_tuple!(value, options).render_value_option(|(value, options)| {
-            options
-                .contains(&value)
-                .not()
-                .then(|| dom! { <option value="" selected="selected" /> })
-        });
+    let empty = computed_tuple!(value, options).render_value_option(|(value, options)| {
+        options
+            .contains(&value)
+            .not()
+            .then(|| dom! { <option value="" selected="selected" /> })
+    });
 
-        let list = bind!(
-            options,
-            value.render_value(move |value| render_list(
-                &options,
-                |item| item.to_string(),
-                move |item| {
-                    let text_item = item.to_string();
-                    if item == &value {
-                        dom! { <option value={&text_item} selected="selected">{text_item}</option> }
-                    } else {
-                        dom! { <option value={&text_item}>{text_item}</option> }
-                    }
+    let list = bind!(
+        options,
+        value.render_value(move |value| render_list(
+            &options,
+            |item| item.to_string(),
+            move |item| {
+                let text_item = item.to_string();
+                if item == &value {
+                    dom! { <option value={&text_item} selected="selected">{text_item}</option> }
+                } else {
+                    dom! { <option value={&text_item}>{text_item}</option> }
                 }
-            ))
-        );
+            }
+        ))
+    );
 
-        dom! {
-            <select {on_change}>
-                {empty}
-                {list}
-            </select>
-        }
+    dom! {
+        <select {on_change} {..attrs}>
+            {empty}
+            {list}
+        </select>
     }
 }
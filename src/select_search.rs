@@ -5,6 +5,8 @@ use vertigo::{
     dom_element, transaction,
 };
 
+use crate::{locale, theme::var};
+
 pub struct SelectSearchParams {
     /// Minimum number of letters to open dropdown
     pub min_chars: usize,
@@ -15,7 +17,7 @@ impl Default for SelectSearchParams {
     fn default() -> Self {
         Self {
             min_chars: 3,
-            input_title: "Enter phrase".to_string(),
+            input_title: locale().enter_phrase.clone(),
         }
     }
 }
@@ -71,9 +73,10 @@ pub fn SelectSearch<K, V>(
         css! {"
             display: {display_value};
             position: fixed;
-            background-color: white;
+            background-color: {var::BACKGROUND};
             box-shadow: 0px 8px 16px 0px rgba(0, 0, 0, 0.4);
-            border: 1px black solid;
+            border: 1px solid;
+            border-color: {var::BORDER};
         "}
     };
 
@@ -85,7 +88,7 @@ pub fn SelectSearch<K, V>(
         dropdown_opened,
         list_deps.render_value(move |(inner_dropdown_opened, inner_items, item_selected)| {
             let item_css = |selected: bool| {
-                let bg_color = if selected { "#ccc" } else { "inherit" };
+                let bg_color = if selected { var::HOVER } else { "inherit" };
 
                 css! {"
                         cursor: pointer;
@@ -93,7 +96,7 @@ pub fn SelectSearch<K, V>(
                         background-color: {bg_color};
 
                         :hover {
-                            background-color: #ccc;
+                            background-color: {var::HOVER};
                         };
                     "}
             };
@@ -1,12 +1,14 @@
 use vertigo::{component, css, dom};
 
+use crate::theme::var;
+
 #[component]
 pub fn Spinner() {
     dom! { <div css={css! {"
         width: 40px;
         height: 40px;
         margin: 100px auto;
-        background-color: #d26913;
+        background-color: {var::SPINNER};
 
         border-radius: 100%;
         animation: 1.0s infinite ease-in-out {
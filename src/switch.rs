@@ -1,5 +1,7 @@
 use vertigo::{AttrGroup, Value, bind, component, dom, transaction};
 
+use crate::locale;
+
 pub enum DisplayType {
     Button,
     CheckBox,
@@ -13,10 +15,11 @@ pub struct SwitchParams {
 
 impl Default for SwitchParams {
     fn default() -> Self {
+        let locale = locale();
         Self {
             display_type: DisplayType::Button,
-            on_symbol: "ON".to_string(),
-            off_symbol: "OFF".to_string(),
+            on_symbol: locale.switch_on.clone(),
+            off_symbol: locale.switch_off.clone(),
         }
     }
 }
@@ -60,8 +63,10 @@ pub fn Switch(value: Value<bool>, params: SwitchParams, i: AttrGroup) {
                 }
             });
 
+            let pressed = value.map(|value| value.to_string());
+
             dom! {
-                <button on_click={toggle} {..i}>{symbol}</button>
+                <button on_click={toggle} aria-pressed={pressed} {..i}>{symbol}</button>
             }
         }
         DisplayType::CheckBox => {
@@ -3,6 +3,8 @@ use vertigo::{
     AttrGroup, Computed, Css, DomNode, Reactive, ToComputed, bind, component, css, dom, dom_element,
 };
 
+use crate::locale;
+
 #[derive(Clone)]
 pub struct Tab<K> {
     pub key: K,
@@ -18,6 +20,8 @@ pub struct TabsParams {
     pub header_active_item_add_css: Css,
     pub content_css: Css,
     pub container_css: Css,
+    /// Additional content (i.e. a badge) rendered after the name of the tab with provided index
+    pub render_header_extra: Option<Rc<dyn Fn(usize) -> DomNode>>,
 }
 
 impl Default for TabsParams {
@@ -38,6 +42,7 @@ impl Default for TabsParams {
             header_active_item_add_css: Css::default(),
             content_css: Css::default(),
             container_css: Css::default(),
+            render_header_extra: None,
         }
     }
 }
@@ -88,7 +93,7 @@ pub fn TabsHeader<R, K>(
         .render_value(move |current_tab_val| {
             let header = dom_element! { <ul css={&params.header_css} {..h.clone()} /> };
 
-            tabs.iter().for_each(|tab| {
+            tabs.iter().enumerate().for_each(|(idx, tab)| {
                 let on_click = bind!(current_tab, tab | _ | current_tab.set(tab.key.clone()));
                 let header_item_css = if current_tab_val == tab.key {
                     &header_item_css + &header_active_item_add_css
@@ -96,9 +101,13 @@ pub fn TabsHeader<R, K>(
                     header_item_css.clone()
                 };
                 let item_css = css! {"display: block;"};
+                let extra = params
+                    .render_header_extra
+                    .as_ref()
+                    .map(|render_extra| render_extra(idx));
                 header.add_child(dom! {
                     <li css={item_css}>
-                        <a  css={header_item_css} on_click={on_click}>{&tab.name}</a>
+                        <a  css={header_item_css} on_click={on_click}>{&tab.name} {..extra}</a>
                     </li>
                 });
             });
@@ -157,7 +166,7 @@ fn render_tab_content<K: PartialEq + Clone>(
 ) -> DomNode {
     let inner = match tabs.iter().find(|tab| &tab.key == effective_tab).cloned() {
         Some(tab) => (tab.render)(current_tab),
-        _ => dom! { <p>"Non-existent tab set"</p> },
+        _ => dom! { <p>{&locale().non_existent_tab}</p> },
     };
 
     dom! {
@@ -0,0 +1,119 @@
+use vertigo::Css;
+
+/// Declares theme tokens, generating [Theme] with presets and references to custom properties.
+macro_rules! theme_tokens {
+    ($(
+        $(#[$meta:meta])*
+        $field:ident, $var:ident, $name:literal: $light:literal, $dark:literal;
+    )*) => {
+        /// Design tokens (palette, spacing, radius and fonts) shared by all components.
+        ///
+        /// Components read tokens from CSS custom properties (i.e. `--vf-primary`), falling back to the light theme.
+        /// Apply [Theme::css] to a root element of the application (or any part of it) to re-skin components inside.
+        ///
+        /// ```rust
+        /// use vertigo::{css, dom};
+        /// use vertigo_forms::Theme;
+        ///
+        /// let theme = Theme {
+        ///     primary: "#6f42c1".to_string(),
+        ///     ..Theme::dark()
+        /// };
+        /// assert_eq!(Theme::default(), Theme::light());
+        ///
+        /// dom! {
+        ///     <div css={theme.css() + css! {"padding: 10px;"}}>
+        ///         "Application"
+        ///     </div>
+        /// };
+        /// ```
+        #[derive(Clone, Debug, PartialEq)]
+        pub struct Theme {
+            $(
+                $(#[$meta])*
+                pub $field: String,
+            )*
+        }
+
+        impl Theme {
+            pub fn light() -> Self {
+                Self {
+                    $($field: $light.to_string(),)*
+                }
+            }
+
+            pub fn dark() -> Self {
+                Self {
+                    $($field: $dark.to_string(),)*
+                }
+            }
+
+            /// Declarations of custom properties for all tokens.
+            pub fn css(&self) -> Css {
+                let mut declarations = String::new();
+                $(
+                    declarations += &format!("--vf-{}: {};\n", $name, self.$field);
+                )*
+                Css::string(declarations)
+            }
+        }
+
+        /// References to tokens for use in component styles, with values of light theme as fallbacks.
+        pub(crate) mod var {
+            $(
+                pub const $var: &str = concat!("var(--vf-", $name, ", ", $light, ")");
+            )*
+        }
+    };
+}
+
+theme_tokens! {
+    // Palette
+    /// Main accent (links, selection, primary buttons)
+    primary, PRIMARY, "primary": "#007bff", "#4dabf7";
+    primary_hover, PRIMARY_HOVER, "primary-hover": "#0069d9", "#74c0fc";
+    success, SUCCESS, "success": "#28a745", "#51cf66";
+    danger, DANGER, "danger": "#dc3545", "#ff6b6b";
+    secondary, SECONDARY, "secondary": "#6c757d", "#adb5bd";
+    /// Text on accent backgrounds
+    on_accent, ON_ACCENT, "on-accent": "#fff", "#101418";
+    text, TEXT, "text": "#333", "#e9ecef";
+    text_muted, TEXT_MUTED, "text-muted": "#6c757d", "#adb5bd";
+    background, BACKGROUND, "background": "#fff", "#1e1e1e";
+    /// Subtle background of headers and highlighted areas
+    surface, SURFACE, "surface": "#f8f9fa", "#2a2a2a";
+    hover, HOVER, "hover": "#e9ecef", "#343a40";
+    border, BORDER, "border": "#ced4da", "#495057";
+    border_light, BORDER_LIGHT, "border-light": "#eee", "#343a40";
+    disabled, DISABLED, "disabled": "#ced4da", "#6c757d";
+    warning_background, WARNING_BACKGROUND, "warning-background": "#fff8e1", "#3d3420";
+    warning_border, WARNING_BORDER, "warning-border": "#ffe082", "#8a6d1f";
+    danger_background, DANGER_BACKGROUND, "danger-background": "#fff0f0", "#3b1f22";
+    danger_border, DANGER_BORDER, "danger-border": "#ffcccc", "#7a2e35";
+    danger_text, DANGER_TEXT, "danger-text": "#b02a37", "#ff8787";
+    /// Background of high-contrast buttons
+    contrast, CONTRAST, "contrast": "#232323", "#e9ecef";
+    contrast_hover, CONTRAST_HOVER, "contrast-hover": "#444", "#ced4da";
+    /// Color of the spinner
+    spinner, SPINNER, "spinner": "#d26913", "#f08c00";
+
+    // Spacing
+    spacing_sm, SPACING_SM, "spacing-sm": "4px", "4px";
+    spacing_md, SPACING_MD, "spacing-md": "8px", "8px";
+    spacing_lg, SPACING_LG, "spacing-lg": "16px", "16px";
+
+    // Radius
+    radius_sm, RADIUS_SM, "radius-sm": "4px", "4px";
+    radius_md, RADIUS_MD, "radius-md": "8px", "8px";
+
+    // Fonts
+    font_family, FONT_FAMILY, "font-family": "inherit", "inherit";
+    font_size_sm, FONT_SIZE_SM, "font-size-sm": "0.8rem", "0.8rem";
+    font_size, FONT_SIZE, "font-size": "0.9rem", "0.9rem";
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::light()
+    }
+}
@@ -0,0 +1,33 @@
+use vertigo::{DomNode, Value, dom};
+use vertigo_forms::{
+    CalendarParams, DatePicker, DatePickerParams, DateTimePicker,
+    date::{DateFormat, DateTime},
+};
+
+pub fn date_picker() -> DomNode {
+    let date = Value::default();
+    let date_time = Value::<Option<DateTime>>::default();
+
+    let us_params = DatePickerParams {
+        calendar: CalendarParams {
+            format: DateFormat::us(),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    dom! {
+        <div>
+            <p>
+                "Date: "
+                <DatePicker value={&date} params={} />
+                " " {date.map(|date| date.map(|date| date.to_string()).unwrap_or_default())}
+            </p>
+            <p>
+                "Date and time (US): "
+                <DateTimePicker value={&date_time} params={us_params} />
+                " " {date_time.map(|dt| dt.map(|dt| dt.to_string()).unwrap_or_default())}
+            </p>
+        </div>
+    }
+}
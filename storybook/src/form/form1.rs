@@ -1,6 +1,7 @@
-use vertigo::{Value, bind_rc, component, css, dom};
+use vertigo::{Value, bind_rc, component, css, dom, get_driver};
 use vertigo_forms::form::{
-    DataSection, FieldsetStyle, FormData, FormExport, FormParams, ModelForm,
+    DataSection, FieldExport, FieldsetStyle, FormData, FormExport, FormParams, ModelForm, Regex,
+    ValidationMode,
 };
 
 // Form example 1
@@ -16,12 +17,31 @@ pub struct MyModel {
 impl From<MyModel> for FormData {
     fn from(value: MyModel) -> Self {
         Self::default()
-            .with(DataSection::with_string_field("Slug", "slug", &value.slug))
-            .with(DataSection::with_string_field("Name", "name", &value.name))
+            .with(
+                DataSection::with_string_field("Slug", "slug", &value.slug)
+                    .required()
+                    .pattern(Regex::new("^[a-z0-9-]+$").unwrap())
+                    .help("Used in URLs, lowercase only")
+                    .placeholder("my-model")
+                    .validate_async(|value| async move {
+                        // Simulate server round-trip
+                        get_driver().sleep(500).await;
+                        (value == FieldExport::String("taken".to_string()))
+                            .then(|| "This slug is already taken".to_string())
+                    }),
+            )
+            .with(
+                DataSection::with_string_field("Name", "name", &value.name)
+                    .required()
+                    .max_len(40),
+            )
             .with(
                 DataSection::with_string_field("Dimensions", "dimension_x", &value.dimension_x)
+                    .range(1..=1000)
                     .add_string_field("dimension_y", &value.dimension_y)
-                    .set_fieldset_style(FieldsetStyle::Dimensions),
+                    .range(1..=1000)
+                    .set_fieldset_style(FieldsetStyle::Dimensions)
+                    .tooltip(|| dom! { <span>"Width and height in millimeters"</span> }),
             )
             .add_bottom_controls()
     }
@@ -59,6 +79,7 @@ pub fn Form1() {
                 {on_submit}
                 params={FormParams {
                     add_css: css! {"width: 400px;"},
+                    validation_mode: ValidationMode::OnBlur,
                     ..Default::default()
                 }}
             />
@@ -1,6 +1,12 @@
 use std::rc::Rc;
-use vertigo::{Value, bind_rc, component, css, dom};
-use vertigo_forms::form::{DataSection, FormData, FormExport, FormParams, ModelForm};
+use vertigo::{Value, bind, component, css, dom, get_driver};
+use vertigo_forms::{
+    RadioGroupParams,
+    form::{
+        AsyncModelForm, AsyncSubmitFunc, DataSection, FormData, FormExport, FormHistory, FormModel,
+        FormParams, GroupParams, NumberParams,
+    },
+};
 
 // Form example 2
 
@@ -28,13 +34,49 @@ impl From<String> for Gender {
     }
 }
 
+#[derive(Clone, Default, PartialEq, FormModel)]
+pub struct Contact {
+    pub name: String,
+    pub phone: String,
+}
+
+#[derive(Clone, PartialEq)]
+pub struct Address {
+    pub street: String,
+    pub city: String,
+}
+
+impl From<Address> for FormData {
+    fn from(value: Address) -> Self {
+        Self::default()
+            .with(DataSection::with_string_field(
+                "Street",
+                "street",
+                &value.street,
+            ))
+            .with(DataSection::with_string_field("City", "city", &value.city).required())
+    }
+}
+
+impl From<FormExport> for Address {
+    fn from(form_export: FormExport) -> Self {
+        Self {
+            street: form_export.get_string("street"),
+            city: form_export.get_string("city"),
+        }
+    }
+}
+
 #[derive(Clone, PartialEq)]
 pub struct MySecondModel {
     pub first_name: String,
     pub surname: String,
     pub gender: Gender,
     pub role: i64,
+    pub age: Option<u32>,
     pub photo: String,
+    pub address: Address,
+    pub contacts: Vec<Contact>,
 }
 
 impl From<MySecondModel> for FormData {
@@ -60,17 +102,47 @@ impl From<MySecondModel> for FormData {
                 "surname",
                 &value.surname,
             ))
-            .with(DataSection::new("Gender").add_list_field(
+            .with(
+                DataSection::new("Full name").add_computed_field("full_name", false, |values| {
+                    format!(
+                        "{} {}",
+                        values.get_string("first_name"),
+                        values.get_string("surname")
+                    )
+                }),
+            )
+            .with(DataSection::new("Gender").add_radio_field(
                 "gender",
                 Some(value.gender.to_string()),
                 gender_map,
+                RadioGroupParams::segmented(),
             ))
             .with(DataSection::new("Role").add_static_dict_field(
                 "role",
                 Some(value.role),
                 role_map,
             ))
-            .with(DataSection::new("Photo").add_image_field("photo", Some(&value.photo)))
+            .with(
+                DataSection::new("Age")
+                    .add_number_field(
+                        "age",
+                        value.age,
+                        NumberParams::integer().min(0.0).max(150.0),
+                    )
+                    .enabled_when(|values| !values.get_string("first_name").is_empty()),
+            )
+            .with(
+                DataSection::new("Photo")
+                    .add_image_field("photo", Some(&value.photo))
+                    // Viewers have no photo
+                    .visible_when(|values| values.dict_or_default::<i64>("role") != 4),
+            )
+            .with(DataSection::new("Address").add_nested_field("address", value.address.into()))
+            .with(DataSection::new("Contacts").add_model_group_field(
+                "contacts",
+                &value.contacts,
+                GroupParams::default().add_label("Add contact"),
+            ))
             .add_bottom_controls()
     }
 }
@@ -82,7 +154,10 @@ impl From<FormExport> for MySecondModel {
             surname: form_export.get_string("surname"),
             gender: form_export.list("gender").unwrap_or(Gender::Male),
             role: form_export.dict_or_default("role"),
+            age: form_export.get_number("age"),
             photo: form_export.image_url("photo"),
+            address: form_export.nested("address").into(),
+            contacts: form_export.group_into("contacts"),
         }
     }
 }
@@ -94,22 +169,63 @@ pub fn Form2() {
         surname: "Gambolputty".to_string(),
         gender: Gender::Male,
         role: 1,
+        age: Some(42),
         photo: "https://picsum.photos/200".to_string(),
+        address: Address {
+            street: "Main St 1".to_string(),
+            city: "Springfield".to_string(),
+        },
+        contacts: vec![Contact {
+            name: "Arthur".to_string(),
+            phone: "555-0100".to_string(),
+        }],
     });
 
-    let on_submit = bind_rc!(my_second_model, |new_model: MySecondModel| {
-        my_second_model.set(new_model);
-    });
+    // Simulate saving on a server, which rejects some names
+    let on_submit: AsyncSubmitFunc<MySecondModel> = {
+        let my_second_model = my_second_model.clone();
+        Rc::new(move |new_model: MySecondModel| {
+            let my_second_model = my_second_model.clone();
+            Box::pin(async move {
+                get_driver().sleep(1000).await;
+                if new_model.surname == "Smith" {
+                    return Err("Server error: Smiths are not allowed".to_string());
+                }
+                my_second_model.set(new_model);
+                Ok(())
+            })
+        })
+    };
+
+    let history = FormHistory::default().with_shortcuts();
+    let undo_disabled = history
+        .can_undo()
+        .map(|can| (!can).then(|| "disabled".to_string()));
+    let redo_disabled = history
+        .can_redo()
+        .map(|can| (!can).then(|| "disabled".to_string()));
+    let on_undo = bind!(history, |_| history.undo());
+    let on_redo = bind!(history, |_| history.redo());
 
     dom! {
         <div>
             <h4>"Form 2:"</h4>
-            <ModelForm
+            <p>
+                <button disabled={undo_disabled} on_click={on_undo}>"Undo"</button>
+                <button disabled={redo_disabled} on_click={on_redo}>"Redo"</button>
+            </p>
+            <AsyncModelForm
                 model={my_second_model.clone()}
                 {on_submit}
                 params={FormParams {
                     add_css: css! {"width: 400px;"},
                     submit_label: Rc::new("Apply".to_string()),
+                    dirty_section_css: Some(css! {"background-color: lightyellow;"}),
+                    submit_only_when_dirty: true,
+                    reset_label: Some(Rc::new("Reset".to_string())),
+                    draft_key: Some(Rc::new("storybook-form2".to_string())),
+                    draft_version: 1,
+                    history: Some(history),
                     ..Default::default()
                 }}
             />
@@ -121,6 +237,15 @@ pub fn Form2() {
                 " (" {my_second_model.map(|m| m.gender.to_string())} ")"
             </p>
             <p>"Role: " {my_second_model.map(|m| m.role)}</p>
+            <p>"Age: " {my_second_model.map(|m| m.age.map(|age| age.to_string()).unwrap_or_default())}</p>
+            <p>
+                "Address: "
+                {my_second_model.map(|m| format!("{}, {}", m.address.street, m.address.city))}
+            </p>
+            <p>
+                "Contacts: "
+                {my_second_model.map(|m| m.contacts.iter().map(|c| format!("{} ({})", c.name, c.phone)).collect::<Vec<_>>().join(", "))}
+            </p>
             <p>
                 "Photo:" <br />
                 <img src={my_second_model.map(|m| m.photo) } />
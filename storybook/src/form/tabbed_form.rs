@@ -1,8 +1,12 @@
 use std::rc::Rc;
 
 use vertigo::{Value, bind_rc, component, css, dom};
-use vertigo_forms::form::{
-    DataFieldValue, DataSection, FormData, FormExport, FormParams, ModelForm, TextAreaValue,
+use vertigo_forms::{
+    date::{Date, DateFormat, DateTime, Time},
+    form::{
+        DataFieldValue, DataSection, DateParams, FormData, FormExport, FormMode, FormParams,
+        ModelForm, TextAreaValue,
+    },
 };
 
 use crate::bordered_tabs;
@@ -14,6 +18,9 @@ pub struct TModel {
     pub first_name: String,
     pub last_name: String,
     pub annotation: Option<String>,
+    pub birth_date: Option<Date>,
+    pub wake_up: Option<Time>,
+    pub next_meeting: Option<DateTime>,
 }
 
 impl From<TModel> for FormData {
@@ -22,21 +29,41 @@ impl From<TModel> for FormData {
             .add_tab(
                 "Basic",
                 vec![
-                    DataSection::with_string_field("First name", "first_name", &value.first_name),
+                    DataSection::with_string_field("First name", "first_name", &value.first_name)
+                        .required(),
                     DataSection::with_string_field("Last name", "last_name", &value.last_name),
+                    DataSection::new("Birth date").add_date_field(
+                        "birth_date",
+                        value.birth_date,
+                        DateParams::default()
+                            .max(Date::new(2020, 12, 31).unwrap())
+                            .format(DateFormat::european()),
+                    ),
                 ],
             )
             .add_tab(
                 "Other",
-                vec![DataSection::new("Annotation").add_field(
-                    "annotation",
-                    DataFieldValue::TextArea(TextAreaValue {
-                        value: Value::new(value.annotation.clone().unwrap_or_default()),
-                        original_value: value.annotation.clone().map(Rc::new),
-                        rows: Some(10),
-                        cols: None,
-                    }),
-                )],
+                vec![
+                    DataSection::new("Annotation").add_field(
+                        "annotation",
+                        DataFieldValue::TextArea(TextAreaValue {
+                            value: Value::new(value.annotation.clone().unwrap_or_default()),
+                            original_value: value.annotation.clone().map(Rc::new),
+                            rows: Some(10),
+                            cols: None,
+                        }),
+                    ),
+                    DataSection::new("Wake up").add_time_field(
+                        "wake_up",
+                        value.wake_up,
+                        DateParams::default().format(DateFormat::us()),
+                    ),
+                    DataSection::new("Next meeting").add_date_time_field(
+                        "next_meeting",
+                        value.next_meeting,
+                        DateParams::default(),
+                    ),
+                ],
             )
             .add_top_controls()
     }
@@ -48,6 +75,9 @@ impl From<FormExport> for TModel {
             first_name: form_export.get_string("first_name"),
             last_name: form_export.get_string("last_name"),
             annotation: form_export.get_string_opt("annotation"),
+            birth_date: form_export.get_date("birth_date"),
+            wake_up: form_export.get_time("wake_up"),
+            next_meeting: form_export.get_date_time("next_meeting"),
         }
     }
 }
@@ -58,22 +88,43 @@ pub fn TabbedForm() {
         first_name: "Johann".to_string(),
         last_name: "Gambolputty".to_string(),
         annotation: None,
+        birth_date: Date::new(1980, 5, 17),
+        wake_up: Time::new(6, 30, 0),
+        next_meeting: None,
     });
 
     let on_submit = bind_rc!(model, |new_model: TModel| {
         model.set(new_model);
     });
+    let on_wizard_submit = on_submit.clone();
 
     dom! {
         <div>
             <h4>"Tabbed Form:"</h4>
-            <ModelForm {model} {on_submit}
+            <ModelForm model={model.clone()} {on_submit}
+                params={FormParams {
+                    add_css: css! {"width: 400px;"},
+                    tabs_params: Some(bordered_tabs()),
+                    ..Default::default()
+                }}
+            />
+            <h4>"Same as wizard:"</h4>
+            <ModelForm model={model.clone()} on_submit={on_wizard_submit}
                 params={FormParams {
                     add_css: css! {"width: 400px;"},
+                    mode: FormMode::Wizard,
                     tabs_params: Some(bordered_tabs()),
                     ..Default::default()
                 }}
             />
+            <h4>"Model:"</h4>
+            <p>
+                {model.map(|m| m.birth_date.map(|d| d.to_string()).unwrap_or_default())}
+                " / "
+                {model.map(|m| m.wake_up.map(|t| t.to_string()).unwrap_or_default())}
+                " / "
+                {model.map(|m| m.next_meeting.map(|dt| dt.to_string()).unwrap_or_default())}
+            </p>
         </div>
     }
 }
@@ -1,7 +1,8 @@
 use std::{fmt, rc::Rc};
-use vertigo::{DomNode, css, dom, main, router::Router};
-use vertigo_forms::{Tab, Tabs, TabsParams};
+use vertigo::{Css, DomNode, Value, css, dom, main, router::Router};
+use vertigo_forms::{Switch, Tab, Tabs, TabsParams, Theme};
 
+mod date_picker;
 mod drop_image_file;
 mod form;
 mod input;
@@ -26,6 +27,7 @@ fn render() -> DomNode {
         MultiSelect,
         MultiDropDown,
         Switch,
+        DatePicker,
         Select,
         SelectSearch,
         SearchPanel,
@@ -46,6 +48,7 @@ fn render() -> DomNode {
                 "/multi_select" => Self::MultiSelect,
                 "/multi_drop_down" => Self::MultiDropDown,
                 "/switch" => Self::Switch,
+                "/date_picker" => Self::DatePicker,
                 "/select" => Self::Select,
                 "/select_search" => Self::SelectSearch,
                 "/search_panel" => Self::SearchPanel,
@@ -69,6 +72,7 @@ fn render() -> DomNode {
                 Self::MultiSelect => write!(f, "/multi_select"),
                 Self::MultiDropDown => write!(f, "/multi_drop_down"),
                 Self::Switch => write!(f, "/switch"),
+                Self::DatePicker => write!(f, "/date_picker"),
                 Self::Select => write!(f, "/select"),
                 Self::SelectSearch => write!(f, "/select_search"),
                 Self::SearchPanel => write!(f, "/search_panel"),
@@ -99,6 +103,11 @@ fn render() -> DomNode {
             name: "Switch".to_string(),
             render: Rc::new(|_| switch::switch()),
         },
+        Tab {
+            key: TabRoute::DatePicker,
+            name: "Date Picker".to_string(),
+            render: Rc::new(|_| date_picker::date_picker()),
+        },
         Tab {
             key: TabRoute::Select,
             name: "Select".to_string(),
@@ -163,12 +172,27 @@ fn render() -> DomNode {
 
     let current_tab = Router::<TabRoute>::new_history_router();
 
+    let dark_mode = Value::new(false);
+    let body_css = dark_mode.map(|dark_mode| {
+        let theme = if dark_mode {
+            Theme::dark()
+        } else {
+            Theme::light()
+        };
+        let colors = format!("background: {}; color: {};", theme.background, theme.text);
+        theme.css() + Css::string(colors)
+    });
+
     dom! {
         <html>
             <head>
                 <title>"Vertigo Forms Storybook"</title>
             </head>
-            <body>
+            <body css={body_css}>
+                    <p>
+                        "Dark mode: "
+                        <Switch value={&dark_mode} params={} />
+                    </p>
                     <Tabs
                         {&current_tab}
                         {tabs}
@@ -182,14 +206,14 @@ fn render() -> DomNode {
 pub fn bordered_tabs() -> TabsParams {
     TabsParams {
         header_item_add_css: css! {"
-            border: 1px solid black;
+            border: 1px solid currentColor;
             padding: 0px 10px;
         "},
         header_active_item_add_css: css! {"
-            background-color: lightgray;
+            background-color: var(--vf-hover);
         "},
         content_css: css! {"
-            border: solid 1px black;
+            border: solid 1px currentColor;
             padding: 5px 10px;
         "},
         container_css: css! {"
@@ -1,5 +1,5 @@
 use vertigo::{DomNode, Value, dom};
-use vertigo_forms::Select;
+use vertigo_forms::{RadioGroup, RadioGroupParams, Select};
 
 pub fn select() -> DomNode {
     let value = Value::new("foo".to_string());
@@ -13,8 +13,24 @@ pub fn select() -> DomNode {
         <p>
             "Select value: "
             <Select
+                value={value.clone()}
+                options={options.clone()}
+            />
+        </p>
+        <p>
+            "Radio group: "
+            <RadioGroup
+                value={value.clone()}
+                options={options.clone()}
+                params={RadioGroupParams::horizontal()}
+            />
+        </p>
+        <p>
+            "Segmented: "
+            <RadioGroup
                 value={value.clone()}
                 options={options}
+                params={RadioGroupParams::segmented()}
             />
         </p>
         <p>
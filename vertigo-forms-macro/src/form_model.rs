@@ -0,0 +1,418 @@
+use darling::{FromDeriveInput, FromField, FromMeta, ast::Data, util::Ignored};
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{DeriveInput, Expr, GenericArgument, Ident, PathArguments, Type};
+
+#[derive(FromDeriveInput)]
+#[darling(attributes(form), supports(struct_named))]
+struct FormModelInput {
+    ident: Ident,
+    generics: syn::Generics,
+    data: Data<Ignored, FormModelField>,
+    #[darling(default)]
+    top_controls: bool,
+    #[darling(default)]
+    bottom_controls: bool,
+}
+
+#[derive(FromField)]
+#[darling(attributes(form))]
+struct FormModelField {
+    ident: Option<Ident>,
+    ty: Type,
+    label: Option<String>,
+    key: Option<String>,
+    #[darling(default)]
+    kind: FieldKind,
+    options: Option<Expr>,
+    rows: Option<i32>,
+    cols: Option<i32>,
+    section: Option<String>,
+    fieldset: Option<Fieldset>,
+    tab: Option<String>,
+    #[darling(default)]
+    skip: bool,
+}
+
+#[derive(Clone, Copy, Default, FromMeta, PartialEq)]
+enum FieldKind {
+    #[default]
+    String,
+    #[darling(rename = "textarea")]
+    TextArea,
+    List,
+    Radio,
+    Dict,
+    Number,
+    Date,
+    Time,
+    #[darling(rename = "datetime")]
+    DateTime,
+    Bool,
+    Image,
+    Group,
+    Nested,
+}
+
+#[derive(Clone, Copy, FromMeta)]
+enum Fieldset {
+    Plain,
+    Dimensions,
+}
+
+/// Fields grouped into one `DataSection`
+struct Section<'a> {
+    name: Option<&'a str>,
+    tab: Option<&'a str>,
+    fields: Vec<&'a FormModelField>,
+}
+
+pub(crate) fn derive(input: DeriveInput) -> darling::Result<TokenStream> {
+    let input = FormModelInput::from_derive_input(&input)?;
+    let fields = input
+        .data
+        .take_struct()
+        .map(|fields| fields.fields)
+        .unwrap_or_default();
+
+    let mut errors = darling::Error::accumulator();
+
+    // Group fields into sections, preserving order of first appearance
+    let mut sections: Vec<Section> = vec![];
+    for field in fields.iter().filter(|field| !field.skip) {
+        let existing = field.section.as_deref().and_then(|name| {
+            sections
+                .iter_mut()
+                .find(|section| section.name == Some(name))
+        });
+        match existing {
+            Some(section) => {
+                match (section.tab, field.tab.as_deref()) {
+                    (_, None) => {}
+                    (None, tab) => section.tab = tab,
+                    (Some(tab), Some(field_tab)) if tab != field_tab => errors.push(
+                        darling::Error::custom(format!(
+                            "section is already put into tab \"{tab}\""
+                        ))
+                        .with_span(&field.ident),
+                    ),
+                    _ => {}
+                }
+                section.fields.push(field);
+            }
+            None => sections.push(Section {
+                name: field.section.as_deref(),
+                tab: field.tab.as_deref(),
+                fields: vec![field],
+            }),
+        }
+    }
+
+    // Sections outside of tabs
+    let mut form_data_builder = vec![];
+    for section in sections.iter().filter(|section| section.tab.is_none()) {
+        if let Some(section) = errors.handle(render_section(section)) {
+            form_data_builder.push(quote! { .with(#section) });
+        }
+    }
+
+    // Tabs, in order of first appearance
+    let mut tab_labels: Vec<&str> = vec![];
+    for tab in sections.iter().filter_map(|section| section.tab) {
+        if !tab_labels.contains(&tab) {
+            tab_labels.push(tab);
+        }
+    }
+    for tab in tab_labels {
+        let mut tab_sections = vec![];
+        for section in sections.iter().filter(|section| section.tab == Some(tab)) {
+            if let Some(section) = errors.handle(render_section(section)) {
+                tab_sections.push(section);
+            }
+        }
+        form_data_builder.push(quote! { .add_tab(#tab, vec![#(#tab_sections),*]) });
+    }
+
+    if input.top_controls {
+        form_data_builder.push(quote! { .add_top_controls() });
+    }
+    if input.bottom_controls {
+        form_data_builder.push(quote! { .add_bottom_controls() });
+    }
+
+    let model_fields = fields
+        .iter()
+        .map(|field| errors.handle(render_export(field)))
+        .collect::<Vec<_>>();
+
+    errors.finish()?;
+
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::core::convert::From<#ident #ty_generics> for ::vertigo_forms::form::FormData #where_clause {
+            fn from(value: #ident #ty_generics) -> Self {
+                ::vertigo_forms::form::FormData::default()
+                    #(#form_data_builder)*
+            }
+        }
+
+        impl #impl_generics ::core::convert::From<::vertigo_forms::form::FormExport> for #ident #ty_generics #where_clause {
+            fn from(form_export: ::vertigo_forms::form::FormExport) -> Self {
+                Self {
+                    #(#model_fields),*
+                }
+            }
+        }
+    })
+}
+
+fn render_section(section: &Section) -> darling::Result<TokenStream> {
+    let label = section
+        .fields
+        .first()
+        .map(|field| field.label())
+        .unwrap_or_default();
+
+    let mut builder = vec![];
+    let mut fieldset = None;
+    for field in &section.fields {
+        builder.push(render_field(field)?);
+        if let Some(field_fieldset) = field.fieldset {
+            fieldset = Some(field_fieldset);
+        }
+    }
+
+    if let Some(fieldset) = fieldset {
+        let style = match fieldset {
+            Fieldset::Plain => quote! { Plain },
+            Fieldset::Dimensions => quote! { Dimensions },
+        };
+        builder.push(quote! {
+            .set_fieldset_style(::vertigo_forms::form::FieldsetStyle::#style)
+        });
+    }
+
+    Ok(quote! {
+        ::vertigo_forms::form::DataSection::new(#label)
+            #(#builder)*
+    })
+}
+
+fn render_field(field: &FormModelField) -> darling::Result<TokenStream> {
+    let ident = &field.ident;
+    let key = field.key();
+    let optional = field.is_optional();
+
+    let options = || {
+        field.options.as_ref().ok_or_else(|| {
+            darling::Error::custom("`options` attribute is required for this kind of field")
+                .with_span(&field.ident)
+        })
+    };
+
+    Ok(match (field.kind, optional) {
+        (FieldKind::String, false) => quote! { .add_string_field(#key, &value.#ident) },
+        (FieldKind::String, true) => quote! { .add_opt_string_field(#key, &value.#ident) },
+        (FieldKind::TextArea, optional) => {
+            let rows = option_tokens(field.rows);
+            let cols = option_tokens(field.cols);
+            let original_value = if optional {
+                quote! { value.#ident.clone() }
+            } else {
+                quote! { Some(value.#ident.clone()) }
+            };
+            quote! { .add_textarea_field(#key, #original_value, #rows, #cols) }
+        }
+        (FieldKind::List, optional) => {
+            let options = options()?;
+            let original_value = if optional {
+                quote! { value.#ident.as_ref().map(|v| v.to_string()) }
+            } else {
+                quote! { Some(value.#ident.to_string()) }
+            };
+            quote! { .add_list_field(#key, #original_value, #options) }
+        }
+        (FieldKind::Radio, optional) => {
+            let options = options()?;
+            let original_value = if optional {
+                quote! { value.#ident.as_ref().map(|v| v.to_string()) }
+            } else {
+                quote! { Some(value.#ident.to_string()) }
+            };
+            quote! {
+                .add_radio_field(
+                    #key,
+                    #original_value,
+                    #options,
+                    ::vertigo_forms::RadioGroupParams::default(),
+                )
+            }
+        }
+        (FieldKind::Dict, false) => {
+            let options = options()?;
+            quote! {
+                .add_static_dict_field(
+                    #key,
+                    Some(::core::convert::Into::<i64>::into(value.#ident.clone())),
+                    #options,
+                )
+            }
+        }
+        (FieldKind::Dict, true) => {
+            return Err(
+                darling::Error::custom("`Option` is not supported for `dict` fields")
+                    .with_span(&field.ty),
+            );
+        }
+        (FieldKind::Number, _) => {
+            let params = if field.is_float() {
+                quote! { ::vertigo_forms::form::NumberParams::decimal() }
+            } else {
+                quote! { ::vertigo_forms::form::NumberParams::integer() }
+            };
+            if optional {
+                quote! { .add_number_field(#key, value.#ident.as_ref(), #params) }
+            } else {
+                quote! { .add_number_field(#key, Some(&value.#ident), #params) }
+            }
+        }
+        (FieldKind::Date | FieldKind::Time | FieldKind::DateTime, _) => {
+            let builder = match field.kind {
+                FieldKind::Date => quote! { add_date_field },
+                FieldKind::Time => quote! { add_time_field },
+                _ => quote! { add_date_time_field },
+            };
+            let original_value = if optional {
+                quote! { value.#ident }
+            } else {
+                quote! { Some(value.#ident) }
+            };
+            quote! {
+                .#builder(#key, #original_value, ::vertigo_forms::form::DateParams::default())
+            }
+        }
+        (FieldKind::Bool, false) => quote! { .add_bool_field(#key, Some(value.#ident)) },
+        (FieldKind::Bool, true) => quote! { .add_bool_field(#key, value.#ident) },
+        (FieldKind::Image, false) => quote! { .add_image_field(#key, Some(&value.#ident)) },
+        (FieldKind::Image, true) => quote! { .add_image_field(#key, value.#ident.as_ref()) },
+        (FieldKind::Group, false) => quote! {
+            .add_model_group_field(
+                #key,
+                &value.#ident,
+                ::vertigo_forms::form::GroupParams::default(),
+            )
+        },
+        (FieldKind::Nested, false) => quote! {
+            .add_nested_field(
+                #key,
+                ::vertigo_forms::form::FormData::from(::core::clone::Clone::clone(&value.#ident)),
+            )
+        },
+        (FieldKind::Group | FieldKind::Nested, true) => {
+            return Err(darling::Error::custom(
+                "`Option` is not supported for `group` and `nested` fields",
+            )
+            .with_span(&field.ty));
+        }
+    })
+}
+
+fn render_export(field: &FormModelField) -> darling::Result<TokenStream> {
+    let ident = &field.ident;
+
+    if field.skip {
+        return Ok(quote! { #ident: ::core::default::Default::default() });
+    }
+
+    let key = field.key();
+    let getter = match (field.kind, field.is_optional()) {
+        (FieldKind::String | FieldKind::TextArea, false) => quote! { get_string(#key) },
+        (FieldKind::String | FieldKind::TextArea, true) => quote! { get_string_opt(#key) },
+        (FieldKind::List | FieldKind::Radio, false) => quote! { list_or_default(#key) },
+        (FieldKind::List | FieldKind::Radio, true) => quote! { list(#key) },
+        // Option for dict is reported in `render_field`
+        (FieldKind::Dict, _) => quote! { dict_or_default(#key) },
+        (FieldKind::Number, false) => quote! { number_or_default(#key) },
+        (FieldKind::Number, true) => quote! { get_number(#key) },
+        (FieldKind::Date, false) => quote! { date_or_default(#key) },
+        (FieldKind::Date, true) => quote! { get_date(#key) },
+        (FieldKind::Time, false) => quote! { time_or_default(#key) },
+        (FieldKind::Time, true) => quote! { get_time(#key) },
+        (FieldKind::DateTime, false) => quote! { date_time_or_default(#key) },
+        (FieldKind::DateTime, true) => quote! { get_date_time(#key) },
+        (FieldKind::Bool, false) => quote! { get_bool(#key) },
+        (FieldKind::Bool, true) => quote! { get_bool_opt(#key) },
+        (FieldKind::Image, false) => quote! { image_url(#key) },
+        (FieldKind::Image, true) => quote! { image_url_opt(#key) },
+        // Option for group and nested is reported in `render_field`
+        (FieldKind::Group, _) => quote! { group_into(#key) },
+        (FieldKind::Nested, _) => quote! { nested(#key).into() },
+    };
+
+    Ok(quote! { #ident: form_export.#getter })
+}
+
+fn option_tokens(value: Option<i32>) -> TokenStream {
+    match value {
+        Some(value) => quote! { Some(#value) },
+        None => quote! { None },
+    }
+}
+
+impl FormModelField {
+    fn name(&self) -> String {
+        self.ident
+            .as_ref()
+            .map(ToString::to_string)
+            .unwrap_or_default()
+    }
+
+    fn key(&self) -> String {
+        self.key.clone().unwrap_or_else(|| self.name())
+    }
+
+    /// Explicit label or field name converted to a human readable form, i.e. "first_name" -> "First name"
+    fn label(&self) -> String {
+        self.label.clone().unwrap_or_else(|| {
+            let name = self.name().replace('_', " ");
+            let mut chars = name.chars();
+            chars
+                .next()
+                .map(|first| first.to_uppercase().chain(chars).collect())
+                .unwrap_or_default()
+        })
+    }
+
+    fn is_optional(&self) -> bool {
+        option_inner(&self.ty).is_some()
+    }
+
+    /// Type (or type inside `Option`) is `f32` or `f64`
+    fn is_float(&self) -> bool {
+        let ty = option_inner(&self.ty).unwrap_or(&self.ty);
+        let Type::Path(type_path) = ty else {
+            return false;
+        };
+        type_path.path.is_ident("f32") || type_path.path.is_ident("f64")
+    }
+}
+
+/// Returns `T` if provided type is `Option<T>`
+fn option_inner(ty: &Type) -> Option<&Type> {
+    let Type::Path(type_path) = ty else {
+        return None;
+    };
+    let segment = type_path.path.segments.last()?;
+    if segment.ident != "Option" {
+        return None;
+    }
+    match &segment.arguments {
+        PathArguments::AngleBracketed(args) => match args.args.first() {
+            Some(GenericArgument::Type(ty)) => Some(ty),
+            _ => None,
+        },
+        _ => None,
+    }
+}
@@ -0,0 +1,13 @@
+use proc_macro::TokenStream;
+use syn::{DeriveInput, parse_macro_input};
+
+mod form_model;
+
+// Documented at the re-export in `vertigo_forms::form`, where links to its types resolve.
+#[proc_macro_derive(FormModel, attributes(form))]
+pub fn derive_form_model(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    form_model::derive(input)
+        .unwrap_or_else(|err| err.write_errors())
+        .into()
+}